@@ -0,0 +1,1031 @@
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use bson::{doc, Bson, Document, RawDocumentBuf};
+use log::debug;
+use mongodb::options::{
+    Collation, DeleteOptions, Hint, InsertManyOptions, ReplaceOptions, UpdateModifications,
+    UpdateOptions,
+};
+use mongodb::{Client, ClientSession, Collection};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::error::MongoError;
+use crate::options::CoreBulkWriteOptions;
+use crate::result::{CoreBulkWriteError, CoreBulkWriteResult};
+
+/// A single tagged write operation as submitted from Python. The `type` tag mirrors the
+/// operation names used by the other drivers' `bulk_write` APIs.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoreWriteModel {
+    InsertOne {
+        document: RawDocumentBuf,
+    },
+    UpdateOne {
+        filter: Document,
+        update: Bson,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        array_filters: Option<Vec<Document>>,
+        #[serde(default)]
+        hint: Option<Hint>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    UpdateMany {
+        filter: Document,
+        update: Bson,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        array_filters: Option<Vec<Document>>,
+        #[serde(default)]
+        hint: Option<Hint>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    ReplaceOne {
+        filter: Document,
+        replacement: RawDocumentBuf,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    DeleteOne {
+        filter: Document,
+    },
+    DeleteMany {
+        filter: Document,
+    },
+}
+
+impl CoreWriteModel {
+    fn kind(&self) -> &'static str {
+        match self {
+            CoreWriteModel::InsertOne { .. } => "insert_one",
+            CoreWriteModel::UpdateOne { .. } => "update_one",
+            CoreWriteModel::UpdateMany { .. } => "update_many",
+            CoreWriteModel::ReplaceOne { .. } => "replace_one",
+            CoreWriteModel::DeleteOne { .. } => "delete_one",
+            CoreWriteModel::DeleteMany { .. } => "delete_many",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CoreWriteModelList(pub Vec<CoreWriteModel>);
+
+impl<'py> FromPyObject<'_, 'py> for CoreWriteModelList {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let items = obj.extract::<Vec<Vec<u8>>>()?;
+        let mut models = Vec::with_capacity(items.len());
+
+        for bytes in items {
+            let model: CoreWriteModel =
+                bson::from_slice(&bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            models.push(model);
+        }
+
+        Ok(CoreWriteModelList(models))
+    }
+}
+
+// Maximum number of operations coalesced into a single insert_many batch. Mirrors the
+// official drivers' default maxWriteBatchSize.
+const MAX_BATCH_OPS: usize = 100_000;
+// Rough cap on serialized batch size (16MB BSON limit minus headroom for command overhead).
+const MAX_BATCH_BYTES: usize = 16 * 1024 * 1024 - 16 * 1024;
+
+/// Walks `models` in order and coalesces maximal runs of same-kind operations into as few
+/// server round trips as possible. Only `InsertOne` runs can actually be sent as a single
+/// `insertMany` command with the driver API available here; every other kind still gets one
+/// round trip per operation, but grouping keeps the ordered/unordered semantics and error
+/// aggregation uniform across all operation kinds.
+pub async fn execute_bulk_write(
+    collection: &Collection<RawDocumentBuf>,
+    models: Vec<CoreWriteModel>,
+    options: CoreBulkWriteOptions,
+) -> PyResult<CoreBulkWriteResult> {
+    let ordered = options.ordered.unwrap_or(true);
+    let mut result = CoreBulkWriteResult::default();
+
+    let mut index = 0usize;
+    let mut batch_start = 0usize;
+
+    while batch_start < models.len() {
+        let kind = models[batch_start].kind();
+        let mut batch_end = batch_start + 1;
+        let mut batch_bytes = model_size(&models[batch_start]);
+
+        while batch_end < models.len()
+            && models[batch_end].kind() == kind
+            && batch_end - batch_start < MAX_BATCH_OPS
+            && batch_bytes + model_size(&models[batch_end]) <= MAX_BATCH_BYTES
+        {
+            batch_bytes += model_size(&models[batch_end]);
+            batch_end += 1;
+        }
+
+        debug!(
+            "bulk_write batch: kind={}, ops={}, ordered={}",
+            kind,
+            batch_end - batch_start,
+            ordered
+        );
+
+        let failed = run_batch(
+            collection,
+            &models[batch_start..batch_end],
+            index,
+            &options,
+            &mut result,
+        )
+        .await?;
+
+        index += batch_end - batch_start;
+        batch_start = batch_end;
+
+        if failed && ordered {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+fn model_size(model: &CoreWriteModel) -> usize {
+    match model {
+        CoreWriteModel::InsertOne { document } => document.as_bytes().len(),
+        CoreWriteModel::ReplaceOne { replacement, .. } => replacement.as_bytes().len(),
+        _ => 256,
+    }
+}
+
+/// Runs one maximal same-kind batch, recording successes/errors into `result`. Returns
+/// `true` if at least one operation in the batch failed.
+async fn run_batch(
+    collection: &Collection<RawDocumentBuf>,
+    batch: &[CoreWriteModel],
+    start_index: usize,
+    options: &CoreBulkWriteOptions,
+    result: &mut CoreBulkWriteResult,
+) -> PyResult<bool> {
+    let ordered = options.ordered.unwrap_or(true);
+
+    match &batch[0] {
+        CoreWriteModel::InsertOne { .. } => {
+            let documents = batch.iter().map(|m| match m {
+                CoreWriteModel::InsertOne { document } => document.clone(),
+                _ => unreachable!("batch grouped by kind"),
+            });
+
+            let insert_options = InsertManyOptions::builder()
+                .ordered(options.ordered)
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            match collection
+                .insert_many(documents)
+                .with_options(insert_options)
+                .await
+            {
+                Ok(inserted) => {
+                    result.inserted_count += inserted.inserted_ids.len() as u64;
+
+                    if options.verbose_results.unwrap_or(false) {
+                        let mut ids: Vec<(usize, Bson)> = inserted.inserted_ids.into_iter().collect();
+                        ids.sort_by_key(|(i, _)| *i);
+                        for (offset, id) in ids {
+                            result.inserted_ids.push(id.clone());
+                            result.results.push(doc! {
+                                "index": (start_index + offset) as i64,
+                                "type": "insert_one",
+                                "insertedId": id,
+                            });
+                        }
+                    } else {
+                        result
+                            .inserted_ids
+                            .extend(inserted.inserted_ids.into_values());
+                    }
+
+                    Ok(false)
+                }
+                Err(e) => {
+                    record_insert_many_failure(MongoError::from(e), start_index, result);
+                    Ok(true)
+                }
+            }
+        }
+        _ => {
+            let mut any_failed = false;
+
+            for (offset, model) in batch.iter().enumerate() {
+                let op_index = start_index + offset;
+
+                match run_single(collection, model, options).await {
+                    Ok(outcome) => outcome.merge_into(op_index, model.kind(), options, result),
+                    Err(message) => {
+                        any_failed = true;
+                        result
+                            .write_errors
+                            .push(CoreBulkWriteError::from_index_and_message(op_index, message));
+                        if ordered {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            Ok(any_failed)
+        }
+    }
+}
+
+/// Folds an `insert_many` failure into `result`, recording one `CoreBulkWriteError` per
+/// document the server actually rejected (at its real index within the batch) instead of one
+/// opaque error for the whole batch. The driver's `BulkWriteFailure` doesn't report which
+/// documents before the failure point were inserted, so `inserted_count`/`inserted_ids` can't
+/// be recovered for those here -- only the per-document error detail can.
+fn record_insert_many_failure(error: MongoError, start_index: usize, result: &mut CoreBulkWriteResult) {
+    match error.bulk_write_failure() {
+        Some(failure) => {
+            for we in failure.write_errors.iter().flatten() {
+                result
+                    .write_errors
+                    .push(CoreBulkWriteError::from_index_and_message(
+                        start_index + we.index,
+                        we.message.clone(),
+                    ));
+            }
+
+            if let Some(wce) = &failure.write_concern_error {
+                result
+                    .write_errors
+                    .push(CoreBulkWriteError::from_index_and_message(
+                        start_index,
+                        wce.message.clone(),
+                    ));
+            }
+        }
+        None => {
+            result
+                .write_errors
+                .push(CoreBulkWriteError::from_index_and_error(start_index, error));
+        }
+    }
+}
+
+/// Session-bound sibling of `execute_bulk_write`, threading `session` through every operation
+/// so the batch participates in the caller's transaction/causal-consistency guarantees.
+pub async fn execute_bulk_write_with_session(
+    collection: &Collection<RawDocumentBuf>,
+    session: &Arc<Mutex<ClientSession>>,
+    models: Vec<CoreWriteModel>,
+    options: CoreBulkWriteOptions,
+) -> PyResult<CoreBulkWriteResult> {
+    let ordered = options.ordered.unwrap_or(true);
+    let mut result = CoreBulkWriteResult::default();
+
+    let mut index = 0usize;
+    let mut batch_start = 0usize;
+
+    while batch_start < models.len() {
+        let kind = models[batch_start].kind();
+        let mut batch_end = batch_start + 1;
+        let mut batch_bytes = model_size(&models[batch_start]);
+
+        while batch_end < models.len()
+            && models[batch_end].kind() == kind
+            && batch_end - batch_start < MAX_BATCH_OPS
+            && batch_bytes + model_size(&models[batch_end]) <= MAX_BATCH_BYTES
+        {
+            batch_bytes += model_size(&models[batch_end]);
+            batch_end += 1;
+        }
+
+        debug!(
+            "bulk_write_with_session batch: kind={}, ops={}, ordered={}",
+            kind,
+            batch_end - batch_start,
+            ordered
+        );
+
+        let failed = run_batch_with_session(
+            collection,
+            session,
+            &models[batch_start..batch_end],
+            index,
+            &options,
+            &mut result,
+        )
+        .await?;
+
+        index += batch_end - batch_start;
+        batch_start = batch_end;
+
+        if failed && ordered {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Session-bound sibling of `run_batch`.
+async fn run_batch_with_session(
+    collection: &Collection<RawDocumentBuf>,
+    session: &Arc<Mutex<ClientSession>>,
+    batch: &[CoreWriteModel],
+    start_index: usize,
+    options: &CoreBulkWriteOptions,
+    result: &mut CoreBulkWriteResult,
+) -> PyResult<bool> {
+    let ordered = options.ordered.unwrap_or(true);
+
+    match &batch[0] {
+        CoreWriteModel::InsertOne { .. } => {
+            let documents = batch.iter().map(|m| match m {
+                CoreWriteModel::InsertOne { document } => document.clone(),
+                _ => unreachable!("batch grouped by kind"),
+            });
+
+            let insert_options = InsertManyOptions::builder()
+                .ordered(options.ordered)
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            match collection
+                .insert_many(documents)
+                .with_options(insert_options)
+                .session(session.lock().await.deref_mut())
+                .await
+            {
+                Ok(inserted) => {
+                    result.inserted_count += inserted.inserted_ids.len() as u64;
+
+                    if options.verbose_results.unwrap_or(false) {
+                        let mut ids: Vec<(usize, Bson)> = inserted.inserted_ids.into_iter().collect();
+                        ids.sort_by_key(|(i, _)| *i);
+                        for (offset, id) in ids {
+                            result.inserted_ids.push(id.clone());
+                            result.results.push(doc! {
+                                "index": (start_index + offset) as i64,
+                                "type": "insert_one",
+                                "insertedId": id,
+                            });
+                        }
+                    } else {
+                        result
+                            .inserted_ids
+                            .extend(inserted.inserted_ids.into_values());
+                    }
+
+                    Ok(false)
+                }
+                Err(e) => {
+                    record_insert_many_failure(MongoError::from(e), start_index, result);
+                    Ok(true)
+                }
+            }
+        }
+        _ => {
+            let mut any_failed = false;
+
+            for (offset, model) in batch.iter().enumerate() {
+                let op_index = start_index + offset;
+
+                match run_single_with_session(collection, session, model, options).await {
+                    Ok(outcome) => outcome.merge_into(op_index, model.kind(), options, result),
+                    Err(message) => {
+                        any_failed = true;
+                        result
+                            .write_errors
+                            .push(CoreBulkWriteError::from_index_and_message(op_index, message));
+                        if ordered {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            Ok(any_failed)
+        }
+    }
+}
+
+/// Session-bound sibling of `run_single`.
+async fn run_single_with_session(
+    collection: &Collection<RawDocumentBuf>,
+    session: &Arc<Mutex<ClientSession>>,
+    model: &CoreWriteModel,
+    options: &CoreBulkWriteOptions,
+) -> Result<SingleWriteOutcome, String> {
+    let outcome = match model {
+        CoreWriteModel::InsertOne { .. } => unreachable!("handled as a batch"),
+        CoreWriteModel::UpdateOne {
+            filter,
+            update,
+            upsert,
+            array_filters,
+            hint,
+            collation,
+        } => {
+            let update_options = UpdateOptions::builder()
+                .upsert(*upsert)
+                .array_filters(array_filters.clone())
+                .hint(hint.clone())
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            ensure_valid_update(update)?;
+
+            let modifications: UpdateModifications = into_update_modifications(update);
+
+            let result = collection
+                .update_one(filter.clone(), modifications)
+                .with_options(update_options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::UpdateMany {
+            filter,
+            update,
+            upsert,
+            array_filters,
+            hint,
+            collation,
+        } => {
+            let update_options = UpdateOptions::builder()
+                .upsert(*upsert)
+                .array_filters(array_filters.clone())
+                .hint(hint.clone())
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            ensure_valid_update(update)?;
+
+            let modifications: UpdateModifications = into_update_modifications(update);
+
+            let result = collection
+                .update_many(filter.clone(), modifications)
+                .with_options(update_options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::ReplaceOne {
+            filter,
+            replacement,
+            upsert,
+            collation,
+        } => {
+            crate::document::ensure_no_update_operators(replacement).map_err(|e| e.to_string())?;
+
+            let replace_options = ReplaceOptions::builder()
+                .upsert(*upsert)
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .replace_one(filter.clone(), replacement.clone())
+                .with_options(replace_options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::DeleteOne { filter } => {
+            let delete_options = DeleteOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .delete_one(filter.clone())
+                .with_options(delete_options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                deleted_count: result.deleted_count,
+                ..Default::default()
+            }
+        }
+        CoreWriteModel::DeleteMany { filter } => {
+            let delete_options = DeleteOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .delete_many(filter.clone())
+                .with_options(delete_options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                deleted_count: result.deleted_count,
+                ..Default::default()
+            }
+        }
+    };
+
+    Ok(outcome)
+}
+
+#[derive(Default)]
+struct SingleWriteOutcome {
+    matched_count: u64,
+    modified_count: u64,
+    deleted_count: u64,
+    upserted_id: Option<Bson>,
+}
+
+impl SingleWriteOutcome {
+    fn merge_into(
+        self,
+        index: usize,
+        kind: &'static str,
+        options: &CoreBulkWriteOptions,
+        result: &mut CoreBulkWriteResult,
+    ) {
+        if options.verbose_results.unwrap_or(false) {
+            result.results.push(doc! {
+                "index": index as i64,
+                "type": kind,
+                "matchedCount": self.matched_count as i64,
+                "modifiedCount": self.modified_count as i64,
+                "deletedCount": self.deleted_count as i64,
+                "upsertedId": self.upserted_id.clone().unwrap_or(Bson::Null),
+            });
+        }
+
+        result.matched_count += self.matched_count;
+        result.modified_count += self.modified_count;
+        result.deleted_count += self.deleted_count;
+        if let Some(id) = self.upserted_id {
+            result.upserted_count += 1;
+            result.upserted_ids.push(id);
+        }
+    }
+}
+
+async fn run_single(
+    collection: &Collection<RawDocumentBuf>,
+    model: &CoreWriteModel,
+    options: &CoreBulkWriteOptions,
+) -> Result<SingleWriteOutcome, String> {
+    let outcome = match model {
+        CoreWriteModel::InsertOne { .. } => unreachable!("handled as a batch"),
+        CoreWriteModel::UpdateOne {
+            filter,
+            update,
+            upsert,
+            array_filters,
+            hint,
+            collation,
+        } => {
+            let update_options = UpdateOptions::builder()
+                .upsert(*upsert)
+                .array_filters(array_filters.clone())
+                .hint(hint.clone())
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            ensure_valid_update(update)?;
+
+            let modifications: UpdateModifications = into_update_modifications(update);
+
+            let result = collection
+                .update_one(filter.clone(), modifications)
+                .with_options(update_options)
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::UpdateMany {
+            filter,
+            update,
+            upsert,
+            array_filters,
+            hint,
+            collation,
+        } => {
+            let update_options = UpdateOptions::builder()
+                .upsert(*upsert)
+                .array_filters(array_filters.clone())
+                .hint(hint.clone())
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            ensure_valid_update(update)?;
+
+            let modifications: UpdateModifications = into_update_modifications(update);
+
+            let result = collection
+                .update_many(filter.clone(), modifications)
+                .with_options(update_options)
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::ReplaceOne {
+            filter,
+            replacement,
+            upsert,
+            collation,
+        } => {
+            crate::document::ensure_no_update_operators(replacement).map_err(|e| e.to_string())?;
+
+            let replace_options = ReplaceOptions::builder()
+                .upsert(*upsert)
+                .collation(collation.clone())
+                .bypass_document_validation(options.bypass_document_validation)
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .replace_one(filter.clone(), replacement.clone())
+                .with_options(replace_options)
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                matched_count: result.matched_count,
+                modified_count: result.modified_count,
+                deleted_count: 0,
+                upserted_id: result.upserted_id,
+            }
+        }
+        CoreWriteModel::DeleteOne { filter } => {
+            let delete_options = DeleteOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .delete_one(filter.clone())
+                .with_options(delete_options)
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                deleted_count: result.deleted_count,
+                ..Default::default()
+            }
+        }
+        CoreWriteModel::DeleteMany { filter } => {
+            let delete_options = DeleteOptions::builder()
+                .write_concern(options.write_concern.clone())
+                .let_vars(options.let_vars.clone())
+                .comment(options.comment.clone())
+                .build();
+
+            let result = collection
+                .delete_many(filter.clone())
+                .with_options(delete_options)
+                .await
+                .map_err(|e| MongoError::from(e).to_string())?;
+
+            SingleWriteOutcome {
+                deleted_count: result.deleted_count,
+                ..Default::default()
+            }
+        }
+    };
+
+    Ok(outcome)
+}
+
+/// A tagged write operation submitted to `CoreClient.bulk_write`, identical to
+/// [`CoreWriteModel`] except each op also carries the `db.collection` namespace it targets, so
+/// a single call can span many collections (and databases) in one batch.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoreNamespacedWriteModel {
+    InsertOne {
+        namespace: String,
+        document: RawDocumentBuf,
+    },
+    UpdateOne {
+        namespace: String,
+        filter: Document,
+        update: Bson,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        array_filters: Option<Vec<Document>>,
+        #[serde(default)]
+        hint: Option<Hint>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    UpdateMany {
+        namespace: String,
+        filter: Document,
+        update: Bson,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        array_filters: Option<Vec<Document>>,
+        #[serde(default)]
+        hint: Option<Hint>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    ReplaceOne {
+        namespace: String,
+        filter: Document,
+        replacement: RawDocumentBuf,
+        #[serde(default)]
+        upsert: Option<bool>,
+        #[serde(default)]
+        collation: Option<Collation>,
+    },
+    DeleteOne {
+        namespace: String,
+        filter: Document,
+    },
+    DeleteMany {
+        namespace: String,
+        filter: Document,
+    },
+}
+
+impl CoreNamespacedWriteModel {
+    fn namespace(&self) -> &str {
+        match self {
+            CoreNamespacedWriteModel::InsertOne { namespace, .. } => namespace,
+            CoreNamespacedWriteModel::UpdateOne { namespace, .. } => namespace,
+            CoreNamespacedWriteModel::UpdateMany { namespace, .. } => namespace,
+            CoreNamespacedWriteModel::ReplaceOne { namespace, .. } => namespace,
+            CoreNamespacedWriteModel::DeleteOne { namespace, .. } => namespace,
+            CoreNamespacedWriteModel::DeleteMany { namespace, .. } => namespace,
+        }
+    }
+
+    fn into_write_model(self) -> CoreWriteModel {
+        match self {
+            CoreNamespacedWriteModel::InsertOne { document, .. } => {
+                CoreWriteModel::InsertOne { document }
+            }
+            CoreNamespacedWriteModel::UpdateOne {
+                filter,
+                update,
+                upsert,
+                array_filters,
+                hint,
+                collation,
+                ..
+            } => CoreWriteModel::UpdateOne {
+                filter,
+                update,
+                upsert,
+                array_filters,
+                hint,
+                collation,
+            },
+            CoreNamespacedWriteModel::UpdateMany {
+                filter,
+                update,
+                upsert,
+                array_filters,
+                hint,
+                collation,
+                ..
+            } => CoreWriteModel::UpdateMany {
+                filter,
+                update,
+                upsert,
+                array_filters,
+                hint,
+                collation,
+            },
+            CoreNamespacedWriteModel::ReplaceOne {
+                filter,
+                replacement,
+                upsert,
+                collation,
+                ..
+            } => CoreWriteModel::ReplaceOne {
+                filter,
+                replacement,
+                upsert,
+                collation,
+            },
+            CoreNamespacedWriteModel::DeleteOne { filter, .. } => {
+                CoreWriteModel::DeleteOne { filter }
+            }
+            CoreNamespacedWriteModel::DeleteMany { filter, .. } => {
+                CoreWriteModel::DeleteMany { filter }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CoreNamespacedWriteModelList(pub Vec<CoreNamespacedWriteModel>);
+
+impl<'py> FromPyObject<'_, 'py> for CoreNamespacedWriteModelList {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let items = obj.extract::<Vec<Vec<u8>>>()?;
+        let mut models = Vec::with_capacity(items.len());
+
+        for bytes in items {
+            let model: CoreNamespacedWriteModel =
+                bson::from_slice(&bytes).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            models.push(model);
+        }
+
+        Ok(CoreNamespacedWriteModelList(models))
+    }
+}
+
+fn split_namespace(namespace: &str) -> PyResult<(&str, &str)> {
+    namespace.split_once('.').ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "invalid namespace {:?}, expected \"db.collection\"",
+            namespace
+        ))
+    })
+}
+
+/// Walks `models` in order and coalesces maximal runs targeting the same namespace, submitting
+/// each run via [`execute_bulk_write`] against that namespace's collection handle. There is no
+/// native mixed `bulkWrite` command available here, so this is a client-side emulation: it
+/// still gives ordered/unordered semantics and aggregated results across collections and
+/// databases, just with one extra round trip whenever the namespace changes mid-batch.
+pub async fn execute_client_bulk_write(
+    client: &Client,
+    models: Vec<CoreNamespacedWriteModel>,
+    options: CoreBulkWriteOptions,
+) -> PyResult<CoreBulkWriteResult> {
+    let ordered = options.ordered.unwrap_or(true);
+    let mut result = CoreBulkWriteResult::default();
+
+    let mut index = 0usize;
+    let mut batch_start = 0usize;
+
+    while batch_start < models.len() {
+        let namespace = models[batch_start].namespace().to_string();
+        let mut batch_end = batch_start + 1;
+
+        while batch_end < models.len() && models[batch_end].namespace() == namespace {
+            batch_end += 1;
+        }
+
+        let (db_name, coll_name) = split_namespace(&namespace)?;
+        let collection: Collection<RawDocumentBuf> =
+            client.database(db_name).collection(coll_name);
+
+        debug!(
+            "client.bulk_write batch: namespace={}, ops={}, ordered={}",
+            namespace,
+            batch_end - batch_start,
+            ordered
+        );
+
+        let group: Vec<CoreWriteModel> = models[batch_start..batch_end]
+            .iter()
+            .cloned()
+            .map(CoreNamespacedWriteModel::into_write_model)
+            .collect();
+
+        let group_result = execute_bulk_write(&collection, group, options.clone()).await?;
+        let failed = !group_result.write_errors.is_empty();
+
+        merge_group_result(group_result, index, &mut result);
+
+        index += batch_end - batch_start;
+        batch_start = batch_end;
+
+        if failed && ordered {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+fn merge_group_result(group: CoreBulkWriteResult, index_offset: usize, result: &mut CoreBulkWriteResult) {
+    result.inserted_count += group.inserted_count;
+    result.matched_count += group.matched_count;
+    result.modified_count += group.modified_count;
+    result.deleted_count += group.deleted_count;
+    result.upserted_count += group.upserted_count;
+    result.inserted_ids.extend(group.inserted_ids);
+    result.upserted_ids.extend(group.upserted_ids);
+    result
+        .write_errors
+        .extend(group.write_errors.into_iter().map(|e| {
+            CoreBulkWriteError::from_index_and_message(index_offset + e.index, e.message)
+        }));
+    result.results.extend(group.results.into_iter().map(|mut doc| {
+        if let Some(Bson::Int64(index)) = doc.get("index").cloned() {
+            doc.insert("index", index + index_offset as i64);
+        }
+        doc
+    }));
+}
+
+/// Update documents must contain only atomic operators; pipeline-form updates have no such
+/// restriction. Mirrors `CoreCompoundDocument::ensure_valid_update`'s pre-flight check for the
+/// single-operation API.
+fn ensure_valid_update(update: &Bson) -> Result<(), String> {
+    if let Bson::Document(doc) = update {
+        if doc.keys().any(|key| !key.starts_with('$')) {
+            return Err("update document must only contain atomic operators".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn into_update_modifications(update: &Bson) -> UpdateModifications {
+    match update {
+        Bson::Array(stages) => {
+            let pipeline: Vec<Document> = stages
+                .iter()
+                .filter_map(|s| s.as_document().cloned())
+                .collect();
+            UpdateModifications::Pipeline(pipeline)
+        }
+        Bson::Document(doc) => UpdateModifications::Document(doc.clone()),
+        _ => UpdateModifications::Document(Document::new()),
+    }
+}
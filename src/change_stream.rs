@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use bson::Document;
+use futures::TryStreamExt;
+use mongodb::change_stream::event::ChangeStreamEvent;
+use mongodb::ChangeStream;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use tokio::sync::Mutex;
+
+use crate::document::CoreDocument;
+use crate::error::MongoError;
+use crate::runtime::spawn;
+
+/// Wraps a change stream over full (non-raw) events: the event envelope (operationType, ns,
+/// documentKey, updateDescription, resume token, ...) is returned alongside `fullDocument` as
+/// a single BSON document, so Python callers get the same shape the server sends.
+#[pyclass]
+pub struct CoreChangeStream {
+    stream: Arc<Mutex<ChangeStream<ChangeStreamEvent<Document>>>>,
+}
+
+impl CoreChangeStream {
+    pub fn new(stream: ChangeStream<ChangeStreamEvent<Document>>) -> Self {
+        Self {
+            stream: Arc::new(Mutex::new(stream)),
+        }
+    }
+}
+
+#[pymethods]
+impl CoreChangeStream {
+    pub async fn next(&self) -> PyResult<CoreDocument> {
+        let stream = Arc::clone(&self.stream);
+
+        let fut = async move {
+            let event = stream
+                .lock()
+                .await
+                .try_next()
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            match event {
+                Some(event) => {
+                    let doc = bson::to_document(&event)
+                        .expect("Couldn't convert change stream event into a document");
+                    Ok(CoreDocument::from(doc))
+                }
+                None => Err(PyStopAsyncIteration::new_err("StopAsyncIteration")),
+            }
+        };
+
+        spawn(fut).await?
+    }
+
+    /// The resume token for the last event returned (or the stream's initial resume token if
+    /// nothing has been returned yet). Callers should persist this so `resume_after` can be
+    /// passed back in if the process restarts.
+    pub async fn resume_token(&self) -> PyResult<Option<CoreDocument>> {
+        let stream = Arc::clone(&self.stream);
+
+        let fut = async move {
+            let token = stream
+                .lock()
+                .await
+                .resume_token()
+                .map(|t| bson::to_document(&t).expect("Couldn't convert resume token"))
+                .map(CoreDocument::from);
+
+            Ok(token)
+        };
+
+        spawn(fut).await?
+    }
+}
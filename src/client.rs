@@ -1,10 +1,17 @@
+use crate::bulk::{execute_client_bulk_write, CoreNamespacedWriteModelList};
+use crate::change_stream::CoreChangeStream;
 use crate::database::CoreDatabase;
+use crate::document::CorePipeline;
 use crate::error::MongoError;
-use crate::options::{CoreDatabaseOptions, CoreSessionOptions};
+use crate::options::{
+    CoreBulkWriteOptions, CoreChangeStreamOptions, CoreDatabaseOptions, CoreSessionOptions,
+};
+use crate::result::CoreBulkWriteResult;
 use crate::runtime::spawn;
 use crate::session::CoreSession;
+use bson::Document;
 use log::debug;
-use mongodb::options::{ClientOptions, DatabaseOptions, SessionOptions};
+use mongodb::options::{ChangeStreamOptions, ClientOptions, DatabaseOptions, SessionOptions};
 use mongodb::Client;
 use pyo3::prelude::*;
 
@@ -84,6 +91,61 @@ impl CoreClient {
         spawn(fut).await?
     }
 
+    /// Submits a heterogeneous batch of writes spanning many collections (and databases) in
+    /// one logical call, each tagged with the `db.collection` namespace it targets. There's no
+    /// native cross-namespace `bulkWrite` command available here, so this emulates it
+    /// client-side by grouping consecutive same-namespace ops and reusing the same batching
+    /// used by `CoreCollection.bulk_write`.
+    #[pyo3(signature = (models, options=None))]
+    pub async fn bulk_write(
+        &self,
+        models: CoreNamespacedWriteModelList,
+        options: Option<CoreBulkWriteOptions>,
+    ) -> PyResult<CoreBulkWriteResult> {
+        let client = self.client.clone();
+
+        let options = options.unwrap_or_default();
+
+        debug!(
+            "Client.bulk_write, ops: {:?}, options: {:?}",
+            models.0.len(),
+            options
+        );
+
+        let fut = async move { execute_client_bulk_write(&client, models.0, options).await };
+
+        spawn(fut).await?
+    }
+
+    /// Cluster-wide change stream: watches every collection in every database the client can
+    /// see, instead of a single collection or database.
+    #[pyo3(signature = (pipeline=None, options=None))]
+    pub async fn watch(
+        &self,
+        pipeline: Option<CorePipeline>,
+        options: Option<CoreChangeStreamOptions>,
+    ) -> PyResult<CoreChangeStream> {
+        let client = self.client.clone();
+
+        let pipeline: Vec<Document> = pipeline.map(Into::into).unwrap_or_default();
+        let options: Option<ChangeStreamOptions> = options.map(Into::into);
+
+        debug!("Client.watch, pipeline: {:?}, options: {:?}", pipeline, options);
+
+        let fut = async move {
+            let stream = client
+                .watch()
+                .pipeline(pipeline)
+                .with_options(options)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreChangeStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
     pub async fn shutdown(&self) -> PyResult<()> {
         let client = self.client.clone();
 
@@ -5,31 +5,36 @@ use bson::{Document, RawDocumentBuf};
 use futures::TryStreamExt;
 use log::debug;
 use mongodb::options::{
-    AggregateOptions, CountOptions, CreateIndexOptions, DeleteOptions, DistinctOptions,
-    DropCollectionOptions, DropIndexOptions, EstimatedDocumentCountOptions,
+    AggregateOptions, ChangeStreamOptions, CountOptions, CreateIndexOptions, DeleteOptions,
+    DistinctOptions, DropCollectionOptions, DropIndexOptions, EstimatedDocumentCountOptions,
     FindOneAndDeleteOptions, FindOneAndReplaceOptions, FindOneAndUpdateOptions, FindOneOptions,
-    FindOptions, InsertManyOptions, InsertOneOptions, ListIndexesOptions, ReplaceOptions,
-    SelectionCriteria, UpdateModifications, UpdateOptions,
+    FindOptions, IndexOptions, InsertManyOptions, InsertOneOptions, ListIndexesOptions,
+    ReplaceOptions, SelectionCriteria, UpdateModifications, UpdateOptions,
 };
 use mongodb::{Collection, IndexModel};
 use pyo3::prelude::*;
 
+use crate::bulk::{execute_bulk_write, execute_bulk_write_with_session, CoreWriteModelList};
+use crate::change_stream::CoreChangeStream;
 use crate::cursor::{CoreCursor, CoreSessionCursor};
-use crate::document::{CoreCompoundDocument, CoreDocument, CorePipeline, CoreRawDocument};
+use crate::document::{
+    CoreCompoundDocument, CoreDocument, CorePipeline, CoreRawDocument, CoreRawDocumentArray,
+};
 use crate::result::{
-    CoreCreateIndexResult, CoreCreateIndexesResult, CoreDeleteResult, CoreDistinctResult,
-    CoreInsertManyResult, CoreInsertOneResult, CoreUpdateResult, ReadConcernResult,
-    ReadPreferenceResult, WriteConcernResult,
+    CoreBulkWriteResult, CoreCreateIndexResult, CoreCreateIndexesResult, CoreDeleteResult,
+    CoreDistinctResult, CoreInsertManyResult, CoreInsertOneResult, CoreSyncIndexesResult,
+    CoreUpdateResult, ReadConcernResult, ReadPreferenceResult, WriteConcernResult,
 };
 
 use crate::error::MongoError;
 use crate::options::{
-    CoreAggregateOptions, CoreCountOptions, CoreCreateIndexOptions, CoreDeleteOptions,
-    CoreDistinctOptions, CoreDropCollectionOptions, CoreDropIndexOptions,
-    CoreEstimatedCountOptions, CoreFindOneAndDeleteOptions, CoreFindOneAndReplaceOptions,
-    CoreFindOneAndUpdateOptions, CoreFindOneOptions, CoreFindOptions, CoreIndexModel,
-    CoreInsertManyOptions, CoreInsertOneOptions, CoreListIndexesOptions, CoreReplaceOptions,
-    CoreUpdateOptions,
+    CoreAggregateOptions, CoreBulkWriteOptions, CoreChangeStreamOptions, CoreCountOptions,
+    CoreCreateIndexOptions, CoreDeleteOptions, CoreDistinctOptions, CoreDropCollectionOptions,
+    CoreDropIndexOptions, CoreEstimatedCountOptions, CoreFindOneAndDeleteOptions,
+    CoreFindOneAndReplaceOptions, CoreFindOneAndUpdateOptions, CoreFindOneOptions,
+    CoreFindOptions, CoreIndexModel, CoreInsertManyOptions, CoreInsertOneOptions,
+    CoreListIndexesOptions, CoreReplaceOptions, CoreSearchIndexModel, CoreSyncIndexesOptions,
+    CoreUpdateOptions, CoreVectorSearchParams,
 };
 use crate::runtime::spawn;
 use crate::session::CoreSession;
@@ -128,6 +133,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreFindOneAndUpdateOptions>,
     ) -> PyResult<Option<CoreRawDocument>> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -161,6 +168,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreFindOneAndUpdateOptions>,
     ) -> PyResult<Option<CoreRawDocument>> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -196,6 +205,8 @@ impl CoreCollection {
         replacement: CoreRawDocument,
         options: Option<CoreFindOneAndReplaceOptions>,
     ) -> PyResult<Option<CoreRawDocument>> {
+        replacement.ensure_no_update_operators()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -229,6 +240,8 @@ impl CoreCollection {
         replacement: CoreRawDocument,
         options: Option<CoreFindOneAndReplaceOptions>,
     ) -> PyResult<Option<CoreRawDocument>> {
+        replacement.ensure_no_update_operators()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -522,6 +535,130 @@ impl CoreCollection {
         spawn(fut).await?
     }
 
+    /// Runs an Atlas `$vectorSearch` query without making callers hand-build the aggregation
+    /// stage: `params` is turned into `[{"$vectorSearch": {...}}]`, optionally followed by an
+    /// `$addFields` stage attaching the relevance score under `score_field_name`. `num_candidates`
+    /// defaults to `limit * 10` and `exact` switches ANN to ENN, matching
+    /// `CoreVectorSearchParams::into_pipeline`.
+    pub async fn vector_search(&self, params: CoreVectorSearchParams) -> PyResult<CoreCursor> {
+        let collection = self.collection.clone();
+
+        debug!("{:?}.vector_search, params: {:?}", self.full_name, params);
+
+        let pipeline = params.into_pipeline();
+
+        let fut = async move {
+            let cur = collection
+                .aggregate(pipeline)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreCursor::new(cur.with_type()))
+        };
+
+        spawn(fut).await?
+    }
+
+    pub async fn vector_search_with_session(
+        &self,
+        session: Py<CoreSession>,
+        params: CoreVectorSearchParams,
+    ) -> PyResult<CoreSessionCursor> {
+        let collection = self.collection.clone();
+
+        debug!(
+            "{:?}.vector_search_with_session, params: {:?}",
+            self.full_name, params
+        );
+
+        let pipeline = params.into_pipeline();
+
+        let session = Python::with_gil(|py| session.borrow(py).session.clone());
+
+        let fut = async move {
+            let cur = collection
+                .aggregate(pipeline)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreSessionCursor::new(
+                cur.with_type(),
+                Arc::clone(&session),
+            ))
+        };
+
+        spawn(fut).await?
+    }
+
+    #[pyo3(signature = (pipeline=None, options=None))]
+    pub async fn watch(
+        &self,
+        pipeline: Option<CorePipeline>,
+        options: Option<CoreChangeStreamOptions>,
+    ) -> PyResult<CoreChangeStream> {
+        let collection = self.collection.clone();
+
+        let pipeline: Vec<Document> = pipeline.map(Into::into).unwrap_or_default();
+        let options: Option<ChangeStreamOptions> = options.map(Into::into);
+
+        debug!(
+            "{:?}.watch, pipeline: {:?}, options: {:?}",
+            self.full_name, pipeline, options
+        );
+
+        let fut = async move {
+            let stream = collection
+                .watch()
+                .pipeline(pipeline)
+                .with_options(options)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreChangeStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    #[pyo3(signature = (session, pipeline=None, options=None))]
+    pub async fn watch_with_session(
+        &self,
+        session: Py<CoreSession>,
+        pipeline: Option<CorePipeline>,
+        options: Option<CoreChangeStreamOptions>,
+    ) -> PyResult<CoreChangeStream> {
+        let collection = self.collection.clone();
+
+        let pipeline: Vec<Document> = pipeline.map(Into::into).unwrap_or_default();
+        let options: Option<ChangeStreamOptions> = options.map(Into::into);
+
+        debug!(
+            "{:?}.watch_with_session, pipeline: {:?}, options: {:?}",
+            self.full_name, pipeline, options
+        );
+
+        let session = Python::with_gil(|py| session.borrow(py).session.clone());
+
+        let fut = async move {
+            let stream = collection
+                .watch()
+                .pipeline(pipeline)
+                .with_options(options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreChangeStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Returns every distinct value `field_name` takes across documents matching `filter`,
+    /// without pulling whole documents back — handy for populating faceted filters or
+    /// enumerating enum-like fields. `options.collation` governs string comparison the same way
+    /// it does for `find`/`update`.
     #[pyo3(signature = (field_name, filter=None, options=None))]
     pub async fn distinct(
         &self,
@@ -595,6 +732,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreUpdateOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -630,6 +769,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreUpdateOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -665,6 +806,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreUpdateOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -698,6 +841,8 @@ impl CoreCollection {
         update: CoreCompoundDocument,
         options: Option<CoreUpdateOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        update.ensure_valid_update()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -793,12 +938,12 @@ impl CoreCollection {
     #[pyo3(signature = (documents, options=None))]
     pub async fn insert_many(
         &self,
-        documents: Vec<CoreRawDocument>,
+        documents: CoreRawDocumentArray,
         options: Option<CoreInsertManyOptions>,
     ) -> PyResult<CoreInsertManyResult> {
         let collection = self.collection.clone();
 
-        let documents: Vec<RawDocumentBuf> = documents.into_iter().map(|d| d.into()).collect();
+        let documents: Vec<RawDocumentBuf> = documents.into_documents()?;
         let options: Option<InsertManyOptions> = options.map(|o| o.into());
 
         debug!(
@@ -824,12 +969,12 @@ impl CoreCollection {
     pub async fn insert_many_with_session(
         &self,
         session: Py<CoreSession>,
-        documents: Vec<CoreRawDocument>,
+        documents: CoreRawDocumentArray,
         options: Option<CoreInsertManyOptions>,
     ) -> PyResult<CoreInsertManyResult> {
         let collection = self.collection.clone();
 
-        let documents: Vec<RawDocumentBuf> = documents.into_iter().map(|d| d.into()).collect();
+        let documents: Vec<RawDocumentBuf> = documents.into_documents()?;
         let options: Option<InsertManyOptions> = options.map(|o| o.into());
 
         debug!(
@@ -854,6 +999,59 @@ impl CoreCollection {
         spawn(fut).await?
     }
 
+    /// Batches heterogeneous `CoreWriteModel` ops (insert/update/replace/delete) into as few
+    /// round trips as `execute_bulk_write`'s same-kind grouping allows, honoring
+    /// `options.ordered` for early-stop vs continue-past-failure semantics. See
+    /// `bulk_write_with_session` for the transaction-aware twin.
+    #[pyo3(signature = (models, options=None))]
+    pub async fn bulk_write(
+        &self,
+        models: CoreWriteModelList,
+        options: Option<CoreBulkWriteOptions>,
+    ) -> PyResult<CoreBulkWriteResult> {
+        let collection = self.collection.clone();
+
+        let options = options.unwrap_or_default();
+
+        debug!(
+            "{:?}.bulk_write, ops: {:?}, options: {:?}",
+            self.full_name,
+            models.0.len(),
+            options
+        );
+
+        let fut = async move { execute_bulk_write(&collection, models.0, options).await };
+
+        spawn(fut).await?
+    }
+
+    #[pyo3(signature = (session, models, options=None))]
+    pub async fn bulk_write_with_session(
+        &self,
+        session: Py<CoreSession>,
+        models: CoreWriteModelList,
+        options: Option<CoreBulkWriteOptions>,
+    ) -> PyResult<CoreBulkWriteResult> {
+        let collection = self.collection.clone();
+
+        let options = options.unwrap_or_default();
+
+        debug!(
+            "{:?}.bulk_write_with_session, ops: {:?}, options: {:?}",
+            self.full_name,
+            models.0.len(),
+            options
+        );
+
+        let session = Python::with_gil(|py| session.borrow(py).session.clone());
+
+        let fut = async move {
+            execute_bulk_write_with_session(&collection, &session, models.0, options).await
+        };
+
+        spawn(fut).await?
+    }
+
     #[pyo3(signature = (filter, replacement, options=None))]
     pub async fn replace_one(
         &self,
@@ -861,6 +1059,8 @@ impl CoreCollection {
         replacement: CoreRawDocument,
         options: Option<CoreReplaceOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        replacement.ensure_no_update_operators()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -894,6 +1094,8 @@ impl CoreCollection {
         replacement: CoreRawDocument,
         options: Option<CoreReplaceOptions>,
     ) -> PyResult<CoreUpdateResult> {
+        replacement.ensure_no_update_operators()?;
+
         let collection = self.collection.clone();
 
         let filter: Document = filter.into();
@@ -1449,6 +1651,229 @@ impl CoreCollection {
         spawn(fut).await?
     }
 
+    /// Creates one Atlas Search / Vector Search index, a distinct namespace from the classic
+    /// B-tree indexes above. Returns the created index's name.
+    pub async fn create_search_index(&self, model: CoreSearchIndexModel) -> PyResult<String> {
+        let collection = self.collection.clone();
+
+        let model: SearchIndexModel = model.into();
+
+        debug!("{:?}.create_search_index, model: {:?}", self.full_name, model);
+
+        let fut = async move {
+            collection
+                .create_search_index(model)
+                .await
+                .map_err(|e| MongoError::from(e))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Creates several Atlas Search / Vector Search indexes in one call. Returns the created
+    /// indexes' names, in the same order as `models`.
+    pub async fn create_search_indexes(
+        &self,
+        models: Vec<CoreSearchIndexModel>,
+    ) -> PyResult<Vec<String>> {
+        let collection = self.collection.clone();
+
+        let models: Vec<SearchIndexModel> = models.into_iter().map(|m| m.into()).collect();
+
+        debug!(
+            "{:?}.create_search_indexes, models: {:?}",
+            self.full_name, models
+        );
+
+        let fut = async move {
+            collection
+                .create_search_indexes(models)
+                .await
+                .map_err(|e| MongoError::from(e))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Replaces the definition of the Atlas Search / Vector Search index named `name`. Unlike
+    /// classic indexes, the server updates a search index's definition in place -- no drop and
+    /// recreate needed.
+    pub async fn update_search_index(
+        &self,
+        name: String,
+        definition: CoreDocument,
+    ) -> PyResult<()> {
+        let collection = self.collection.clone();
+
+        let definition: Document = definition.into();
+
+        debug!(
+            "{:?}.update_search_index, name: {:?}, definition: {:?}",
+            self.full_name, name, definition
+        );
+
+        let fut = async move {
+            collection
+                .update_search_index(name, definition)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
+
+    pub async fn drop_search_index(&self, name: String) -> PyResult<()> {
+        let collection = self.collection.clone();
+
+        debug!("{:?}.drop_search_index, name: {:?}", self.full_name, name);
+
+        let fut = async move {
+            collection
+                .drop_search_index(name)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Lists Atlas Search / Vector Search indexes as their raw status documents (`name`,
+    /// `queryable`, latest `latestDefinition`, ...) rather than a typed model, since callers
+    /// mainly need to poll `queryable` until an index finishes building. `name` narrows the
+    /// listing to a single index when given.
+    #[pyo3(signature = (name=None, options=None))]
+    pub async fn list_search_indexes(
+        &self,
+        name: Option<String>,
+        options: Option<CoreAggregateOptions>,
+    ) -> PyResult<CoreCursor> {
+        let collection = self.collection.clone();
+
+        let options: Option<AggregateOptions> = options.map(Into::into);
+
+        debug!(
+            "{:?}.list_search_indexes, name: {:?}, options: {:?}",
+            self.full_name, name, options
+        );
+
+        let fut = async move {
+            let mut action = collection.list_search_indexes();
+            if let Some(name) = name {
+                action = action.name(name);
+            }
+
+            let cur = action
+                .aggregate_options(options)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreCursor::new(cur.with_type()))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Diffs `models` against the indexes already on the server and makes the server match:
+    /// indexes present in both with the same keys/options are left alone, indexes missing from
+    /// the server are created, and indexes present under the same name but with different
+    /// keys/options are dropped and recreated. With `options.prune` set, any existing non-`_id_`
+    /// index absent from `models` is dropped too; `_id_` is never touched.
+    #[pyo3(signature = (models, options=None))]
+    pub async fn sync_indexes(
+        &self,
+        models: Vec<CoreIndexModel>,
+        options: Option<CoreSyncIndexesOptions>,
+    ) -> PyResult<CoreSyncIndexesResult> {
+        let collection = self.collection.clone();
+
+        let models: Vec<IndexModel> = models.into_iter().map(|m| m.into()).collect();
+        let prune = options.and_then(|o| o.prune).unwrap_or(false);
+
+        debug!(
+            "{:?}.sync_indexes, models: {:?}, prune: {:?}",
+            self.full_name, models, prune
+        );
+
+        let fut = async move {
+            let existing: Vec<IndexModel> = collection
+                .list_indexes()
+                .await
+                .map_err(|e| MongoError::from(e))?
+                .try_collect::<Vec<IndexModel>>()
+                .await
+                .map_err(|e| MongoError::from(e))?
+                .into_iter()
+                .filter(|m| index_name(m) != "_id_")
+                .collect();
+
+            let desired_names: std::collections::HashSet<String> =
+                models.iter().map(index_name).collect();
+
+            let mut to_drop: Vec<String> = Vec::new();
+            let mut to_create: Vec<IndexModel> = Vec::new();
+            let mut to_recreate: std::collections::HashSet<String> =
+                std::collections::HashSet::new();
+
+            for model in &models {
+                let name = index_name(model);
+                match existing.iter().find(|m| index_name(m) == name) {
+                    Some(current) if indexes_match(current, model) => {}
+                    Some(_) => {
+                        to_drop.push(name.clone());
+                        to_recreate.insert(name);
+                        to_create.push(model.clone());
+                    }
+                    None => to_create.push(model.clone()),
+                }
+            }
+
+            if prune {
+                for current in &existing {
+                    let name = index_name(current);
+                    if !desired_names.contains(&name) && !to_recreate.contains(&name) {
+                        to_drop.push(name);
+                    }
+                }
+            }
+
+            for name in &to_drop {
+                collection
+                    .drop_index(name)
+                    .await
+                    .map_err(|e| MongoError::from(e))?;
+            }
+
+            let mut result = CoreSyncIndexesResult {
+                dropped: to_drop,
+                ..Default::default()
+            };
+
+            if !to_create.is_empty() {
+                let created = collection
+                    .create_indexes(to_create)
+                    .await
+                    .map_err(|e| MongoError::from(e))?
+                    .index_names;
+
+                for name in created {
+                    if to_recreate.contains(&name) {
+                        result.recreated.push(name);
+                    } else {
+                        result.created.push(name);
+                    }
+                }
+            }
+
+            Ok(result)
+        };
+
+        spawn(fut).await?
+    }
+
     #[pyo3(signature = (options=None))]
     pub async fn drop(&self, options: Option<CoreDropCollectionOptions>) -> PyResult<()> {
         let collection = self.collection.clone();
@@ -1514,3 +1939,39 @@ impl CoreCollection {
         self.collection.read_concern().cloned().map(|wc| wc.into())
     }
 }
+
+/// The name the server would assign this index: the explicit `options.name` if set, otherwise
+/// the standard default of joining each key's `field_direction` pair with `_`.
+fn index_name(model: &IndexModel) -> String {
+    if let Some(name) = model.options.as_ref().and_then(|o| o.name.as_ref()) {
+        return name.clone();
+    }
+
+    model
+        .keys
+        .iter()
+        .map(|(field, direction)| match direction {
+            bson::Bson::String(kind) => format!("{}_{}", field, kind),
+            other => format!("{}_{}", field, other),
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Whether two indexes with the same name are actually the same index, i.e. `sync_indexes` can
+/// leave the existing one in place rather than dropping and recreating it.
+fn indexes_match(a: &IndexModel, b: &IndexModel) -> bool {
+    if a.keys != b.keys {
+        return false;
+    }
+
+    let default_options = IndexOptions::default();
+    let a_options = a.options.as_ref().unwrap_or(&default_options);
+    let b_options = b.options.as_ref().unwrap_or(&default_options);
+
+    a_options.unique == b_options.unique
+        && a_options.sparse == b_options.sparse
+        && a_options.partial_filter_expression == b_options.partial_filter_expression
+        && a_options.expire_after == b_options.expire_after
+        && a_options.collation == b_options.collation
+}
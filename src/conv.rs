@@ -29,5 +29,70 @@ macro_rules! into_py_object {
     };
 }
 
+/// Extracts a raw-BSON newtype wrapping `RawDocumentBuf` directly from the bytes Python hands
+/// over, skipping the owned `bson::Document`/`Bson` tree `from_py_object!` builds for option
+/// structs. For write payloads (inserts, replacements) this avoids decoding every field just to
+/// re-encode it a moment later on the wire.
+#[rustfmt::skip]
+macro_rules! from_py_raw_document {
+    ($t:ident) => {
+        impl<'py> FromPyObject<'_, 'py> for $t {
+            type Error = PyErr;
+
+            fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+                let data = obj.extract::<&[u8]>()?;
+                let doc = bson::RawDocumentBuf::from_bytes(data.into())
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                Ok($t(doc))
+            }
+        }
+    };
+}
+
+/// `IntoPyObject` sibling of `from_py_raw_document!`: hands the raw bytes straight back to
+/// Python, with no intermediate `Document` to re-serialize.
+#[rustfmt::skip]
+macro_rules! into_py_raw_document {
+    ($t:ident) => {
+        impl<'py> IntoPyObject<'py> for $t {
+            type Target = PyBytes;
+            type Output = Bound<'py, Self::Target>;
+            type Error = std::convert::Infallible;
+
+            fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+                Ok(PyBytes::new(py, self.0.as_bytes()))
+            }
+        }
+    };
+}
+
+/// Extracts a raw-BSON newtype wrapping `RawArrayBuf` from a Python list of per-document bytes
+/// (e.g. `insert_many`'s `documents`), building the array one raw document at a time instead of
+/// decoding each into a `Document` first.
+#[rustfmt::skip]
+macro_rules! from_py_raw_array {
+    ($t:ident) => {
+        impl<'py> FromPyObject<'_, 'py> for $t {
+            type Error = PyErr;
+
+            fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+                let items = obj.extract::<Vec<&[u8]>>()?;
+                let mut array = bson::RawArrayBuf::new();
+
+                for bytes in items {
+                    let doc = bson::RawDocumentBuf::from_bytes(bytes.into())
+                        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+                    array.push(doc);
+                }
+
+                Ok($t(array))
+            }
+        }
+    };
+}
+
 pub(crate) use from_py_object;
+pub(crate) use from_py_raw_array;
+pub(crate) use from_py_raw_document;
 pub(crate) use into_py_object;
+pub(crate) use into_py_raw_document;
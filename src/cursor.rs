@@ -1,26 +1,30 @@
 use std::ops::DerefMut;
 use std::sync::Arc;
 
-use bson::{doc, RawDocumentBuf};
+use bson::{doc, Bson, RawDocumentBuf};
 use futures::TryStreamExt;
 use mongodb::{ClientSession, Cursor, SessionCursor};
-use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::exceptions::{PyStopAsyncIteration, PyValueError};
 use pyo3::prelude::*;
 use tokio::sync::Mutex;
 
-use crate::document::CoreRawDocument;
+use crate::document::{CoreProjectedDocument, CoreRawDocument};
 use crate::error::MongoError;
 use crate::runtime::spawn;
 
+fn closed_error() -> PyErr {
+    PyValueError::new_err("cursor is closed")
+}
+
 #[pyclass]
 pub struct CoreCursor {
-    pub cursor: Arc<Mutex<Cursor<RawDocumentBuf>>>,
+    pub cursor: Arc<Mutex<Option<Cursor<RawDocumentBuf>>>>,
 }
 
 impl CoreCursor {
     pub fn new(cursor: Cursor<RawDocumentBuf>) -> Self {
         Self {
-            cursor: Arc::new(Mutex::new(cursor)),
+            cursor: Arc::new(Mutex::new(Some(cursor))),
         }
     }
 }
@@ -30,9 +34,10 @@ impl CoreCursor {
     pub async fn next(&mut self) -> PyResult<CoreRawDocument> {
         let cursor = Arc::clone(&self.cursor);
         let fut = async move {
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
             let result: Option<CoreRawDocument> = cursor
-                .lock()
-                .await
                 .try_next()
                 .await
                 .map_err(|e| MongoError::from(e))?
@@ -53,7 +58,8 @@ impl CoreCursor {
 
         let fut = async move {
             let mut result: Vec<CoreRawDocument> = Vec::new();
-            let mut cursor = cursor.lock().await;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
 
             while let Some(doc) = cursor.try_next().await.map_err(|e| MongoError::from(e))? {
                 result.push(doc.into());
@@ -69,7 +75,8 @@ impl CoreCursor {
         let cursor = Arc::clone(&self.cursor);
         let fut = async move {
             let mut result = Vec::with_capacity(batch_size as usize);
-            let mut cursor = cursor.lock().await;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
 
             for _ in 0..batch_size {
                 let ok = cursor.advance().await.map_err(|e| MongoError::from(e))?;
@@ -91,18 +98,169 @@ impl CoreCursor {
 
         spawn(fut).await?
     }
+
+    /// Like `next_batch`, but concatenates the raw (already length-prefixed) BSON bytes of
+    /// each document back-to-back into one `PyBytes` buffer instead of allocating one Python
+    /// object per document. Returns the buffer plus how many documents it holds; a count of 0
+    /// signals exhaustion.
+    pub async fn next_batch_concat(&mut self, batch_size: u64) -> PyResult<(Vec<u8>, usize)> {
+        let cursor = Arc::clone(&self.cursor);
+        let fut = async move {
+            let mut buf = Vec::new();
+            let mut count = 0usize;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
+            for _ in 0..batch_size {
+                let ok = cursor.advance().await.map_err(|e| MongoError::from(e))?;
+
+                if !ok {
+                    break;
+                }
+
+                buf.extend_from_slice(cursor.current().as_bytes());
+                count += 1;
+            }
+
+            Ok((buf, count))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Like `collect`, but concatenates every remaining document's raw BSON bytes into one
+    /// `PyBytes` buffer, as `next_batch_concat` does per batch.
+    pub async fn collect_concat(&mut self) -> PyResult<(Vec<u8>, usize)> {
+        let cursor = Arc::clone(&self.cursor);
+        let fut = async move {
+            let mut buf = Vec::new();
+            let mut count = 0usize;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
+            while cursor.advance().await.map_err(|e| MongoError::from(e))? {
+                buf.extend_from_slice(cursor.current().as_bytes());
+                count += 1;
+            }
+
+            Ok((buf, count))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Auto-tunes how many documents are pulled per crossing of the async/Python boundary:
+    /// fetches `min` documents, then keeps doubling the target (up to `max`) as long as more
+    /// documents keep arriving before `deadline_ms` elapses. Returns an empty vec once the
+    /// cursor is exhausted.
+    pub async fn next_batch_adaptive(
+        &mut self,
+        min: u64,
+        max: u64,
+        deadline_ms: u64,
+    ) -> PyResult<Vec<CoreRawDocument>> {
+        let cursor = Arc::clone(&self.cursor);
+        let fut = async move {
+            let deadline =
+                tokio::time::Instant::now() + tokio::time::Duration::from_millis(deadline_ms);
+            let mut target = min.max(1);
+            let mut result = Vec::new();
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
+            loop {
+                while (result.len() as u64) < target {
+                    if tokio::time::Instant::now() >= deadline {
+                        return Ok(result);
+                    }
+
+                    let ok = cursor.advance().await.map_err(|e| MongoError::from(e))?;
+
+                    if !ok {
+                        return Ok(result);
+                    }
+
+                    let doc: CoreRawDocument = cursor
+                        .deserialize_current()
+                        .map_err(|e| MongoError::from(e))?
+                        .into();
+
+                    result.push(doc);
+                }
+
+                if target >= max || tokio::time::Instant::now() >= deadline {
+                    break;
+                }
+
+                target = (target * 2).min(max);
+            }
+
+            Ok(result)
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Like `collect`, but instead of handing back the full document, projects each row down
+    /// to just `fields`, in the order given, before it crosses into Python -- skipping the
+    /// decode cost of whatever fields the caller will never read. A field absent from a
+    /// document comes back as `Bson::Null` rather than shrinking that row.
+    pub async fn collect_projected(
+        &mut self,
+        fields: Vec<String>,
+    ) -> PyResult<Vec<CoreProjectedDocument>> {
+        let cursor = Arc::clone(&self.cursor);
+        let fut = async move {
+            let mut rows = Vec::new();
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
+            while cursor.advance().await.map_err(|e| MongoError::from(e))? {
+                let raw = cursor.current();
+
+                let values = fields
+                    .iter()
+                    .map(|field| match raw.get(field) {
+                        Ok(Some(value)) => Bson::try_from(value)
+                            .map_err(|e| PyValueError::new_err(e.to_string())),
+                        Ok(None) => Ok(Bson::Null),
+                        Err(e) => Err(PyValueError::new_err(e.to_string())),
+                    })
+                    .collect::<PyResult<Vec<Bson>>>()?;
+
+                rows.push(CoreProjectedDocument { values });
+            }
+
+            Ok(rows)
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Drops the underlying cursor, triggering the driver's `killCursors` for an unexhausted
+    /// cursor, and marks this handle closed. Any subsequent `next`/`next_batch`/`collect` call
+    /// returns an error instead of panicking on the consumed cursor.
+    pub async fn close(&mut self) -> PyResult<()> {
+        let cursor = Arc::clone(&self.cursor);
+        let fut = async move {
+            cursor.lock().await.take();
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
 }
 
 #[pyclass]
 pub struct CoreSessionCursor {
-    pub cursor: Arc<Mutex<SessionCursor<RawDocumentBuf>>>,
+    pub cursor: Arc<Mutex<Option<SessionCursor<RawDocumentBuf>>>>,
     pub session: Arc<Mutex<ClientSession>>,
 }
 
 impl CoreSessionCursor {
     pub fn new(cursor: SessionCursor<RawDocumentBuf>, session: Arc<Mutex<ClientSession>>) -> Self {
         Self {
-            cursor: Arc::new(Mutex::new(cursor)),
+            cursor: Arc::new(Mutex::new(Some(cursor))),
             session,
         }
     }
@@ -115,10 +273,11 @@ impl CoreSessionCursor {
         let session = Arc::clone(&self.session);
 
         let fut = async move {
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+
             let result: Option<CoreRawDocument> = cursor
-                .lock()
-                .await
-                .next(&mut session.lock().await.deref_mut())
+                .next(session.lock().await.deref_mut())
                 .await
                 .transpose()
                 .map_err(|e| MongoError::from(e))?
@@ -141,12 +300,13 @@ impl CoreSessionCursor {
         let fut = async move {
             let mut result: Vec<CoreRawDocument> = Vec::with_capacity(batch_size as usize);
 
-            let mut cursor = cursor.lock().await;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
             let mut session = session.lock().await;
 
             for _ in 0..batch_size {
                 if let Some(doc) = cursor
-                    .next(&mut session.deref_mut())
+                    .next(session.deref_mut())
                     .await
                     .transpose()
                     .map_err(|e| MongoError::from(e))?
@@ -163,6 +323,122 @@ impl CoreSessionCursor {
         spawn(fut).await?
     }
 
+    /// Like `next_batch`, but concatenates each document's raw BSON bytes into one `PyBytes`
+    /// buffer, as `CoreCursor::next_batch_concat` does.
+    pub async fn next_batch_concat(&mut self, batch_size: u64) -> PyResult<(Vec<u8>, usize)> {
+        let cursor = Arc::clone(&self.cursor);
+        let session = Arc::clone(&self.session);
+
+        let fut = async move {
+            let mut buf = Vec::new();
+            let mut count = 0usize;
+
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+            let mut session = session.lock().await;
+
+            for _ in 0..batch_size {
+                match cursor
+                    .next(session.deref_mut())
+                    .await
+                    .transpose()
+                    .map_err(|e| MongoError::from(e))?
+                {
+                    Some(doc) => {
+                        buf.extend_from_slice(doc.as_bytes());
+                        count += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            Ok((buf, count))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Like `collect`, but concatenates every remaining document's raw BSON bytes into one
+    /// `PyBytes` buffer, as `CoreCursor::collect_concat` does.
+    pub async fn collect_concat(&mut self) -> PyResult<(Vec<u8>, usize)> {
+        let cursor = Arc::clone(&self.cursor);
+        let session = Arc::clone(&self.session);
+
+        let fut = async move {
+            let mut buf = Vec::new();
+            let mut count = 0usize;
+
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+            let mut session = session.lock().await;
+
+            while let Some(doc) = cursor
+                .next(session.deref_mut())
+                .await
+                .transpose()
+                .map_err(|e| MongoError::from(e))?
+            {
+                buf.extend_from_slice(doc.as_bytes());
+                count += 1;
+            }
+
+            Ok((buf, count))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Same auto-tuning batch strategy as `CoreCursor::next_batch_adaptive`, but driving the
+    /// cursor through the held session.
+    pub async fn next_batch_adaptive(
+        &mut self,
+        min: u64,
+        max: u64,
+        deadline_ms: u64,
+    ) -> PyResult<Vec<CoreRawDocument>> {
+        let cursor = Arc::clone(&self.cursor);
+        let session = Arc::clone(&self.session);
+
+        let fut = async move {
+            let deadline =
+                tokio::time::Instant::now() + tokio::time::Duration::from_millis(deadline_ms);
+            let mut target = min.max(1);
+            let mut result: Vec<CoreRawDocument> = Vec::new();
+
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
+            let mut session = session.lock().await;
+
+            loop {
+                while (result.len() as u64) < target {
+                    if tokio::time::Instant::now() >= deadline {
+                        return Ok(result);
+                    }
+
+                    match cursor
+                        .next(session.deref_mut())
+                        .await
+                        .transpose()
+                        .map_err(|e| MongoError::from(e))?
+                    {
+                        Some(doc) => result.push(doc.into()),
+                        None => return Ok(result),
+                    }
+                }
+
+                if target >= max || tokio::time::Instant::now() >= deadline {
+                    break;
+                }
+
+                target = (target * 2).min(max);
+            }
+
+            Ok(result)
+        };
+
+        spawn(fut).await?
+    }
+
     pub async fn collect(&mut self) -> PyResult<Vec<CoreRawDocument>> {
         let cursor = Arc::clone(&self.cursor);
         let session = Arc::clone(&self.session);
@@ -170,7 +446,8 @@ impl CoreSessionCursor {
         let fut = async move {
             let mut result: Vec<CoreRawDocument> = Vec::new();
 
-            let mut cursor = cursor.lock().await;
+            let mut guard = cursor.lock().await;
+            let cursor = guard.as_mut().ok_or_else(closed_error)?;
             let mut session = session.lock().await;
 
             while let Some(doc) = cursor
@@ -187,4 +464,22 @@ impl CoreSessionCursor {
 
         spawn(fut).await?
     }
+
+    /// Drops the underlying session cursor, triggering the driver's `killCursors` in-session
+    /// for an unexhausted cursor, and marks this handle closed. Any subsequent
+    /// `next`/`next_batch`/`collect` call returns an error instead of panicking on the consumed
+    /// cursor.
+    pub async fn close(&mut self) -> PyResult<()> {
+        let cursor = Arc::clone(&self.cursor);
+        let session = Arc::clone(&self.session);
+
+        let fut = async move {
+            let mut guard = cursor.lock().await;
+            let _session = session.lock().await;
+            guard.take();
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
 }
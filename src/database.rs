@@ -1,12 +1,13 @@
+use crate::change_stream::CoreChangeStream;
 use crate::collection::CoreCollection;
 use crate::cursor::{CoreCursor, CoreSessionCursor};
-use crate::document::{CoreDocument, CorePipeline};
+use crate::document::{CoreDocument, CorePipeline, CoreRawDocument};
 use crate::error::MongoError;
 use crate::gridfs::CoreGridFsBucket;
 use crate::options::{
-    CoreAggregateOptions, CoreCollectionOptions, CoreCreateCollectionOptions,
-    CoreDropDatabaseOptions, CoreGridFsBucketOptions, CoreListCollectionsOptions,
-    CoreRunCommandOptions,
+    CoreAggregateOptions, CoreChangeStreamOptions, CoreCollectionOptions,
+    CoreCreateCollectionOptions, CoreDropDatabaseOptions, CoreGridFsBucketOptions,
+    CoreListCollectionsOptions, CoreRunCommandOptions, CoreRunCursorCommandOptions,
 };
 use crate::result::{
     CoreCollectionSpecification, ReadConcernResult, ReadPreferenceResult, WriteConcernResult,
@@ -18,11 +19,12 @@ use futures::TryStreamExt;
 use log::debug;
 use mongodb::action::Action;
 use mongodb::options::{
-    AggregateOptions, CollectionOptions, CreateCollectionOptions, DropDatabaseOptions,
-    GridFsBucketOptions, ListCollectionsOptions, SelectionCriteria,
+    AggregateOptions, ChangeStreamOptions, CollectionOptions, CreateCollectionOptions,
+    DropDatabaseOptions, GridFsBucketOptions, ListCollectionsOptions, SelectionCriteria,
 };
 use mongodb::results::CollectionSpecification;
 use mongodb::Database;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use std::ops::DerefMut;
 use std::sync::Arc;
@@ -233,6 +235,46 @@ impl CoreDatabase {
         spawn(fut).await?
     }
 
+    /// Raw-BSON sibling of `run_command`: takes the command and hands back the reply as
+    /// `RawDocumentBuf` bytes, so callers working in raw BSON don't need to build/tear down a
+    /// full `CoreDocument` on either side of the call. The driver's `Database::run_command`
+    /// only accepts/returns an owned `Document`, though, so an intermediate decode/re-encode
+    /// still happens here on both sides -- this isn't a zero-copy path, just a raw-bytes-in,
+    /// raw-bytes-out one.
+    #[pyo3(signature = (command, options=None))]
+    pub async fn run_command_raw(
+        &self,
+        command: CoreRawDocument,
+        options: Option<CoreRunCommandOptions>,
+    ) -> PyResult<CoreRawDocument> {
+        let db = self.db.clone();
+
+        let command: RawDocumentBuf = command.into();
+        let command: Document =
+            bson::from_slice(command.as_bytes()).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let selection_criteria: Option<SelectionCriteria> = options
+            .and_then(|o| o.read_preference)
+            .map(|p| SelectionCriteria::ReadPreference(p));
+
+        debug!("{:?}.run_command_raw, command: {:?}", self.name, command);
+
+        let fut = async move {
+            let reply = db
+                .run_command(command)
+                .optional(selection_criteria, |cmd, sc| cmd.selection_criteria(sc))
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            let bytes = bson::to_vec(&reply).map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let raw = RawDocumentBuf::from_bytes(bytes.into())
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+            Ok(CoreRawDocument::from(raw))
+        };
+
+        spawn(fut).await?
+    }
+
     #[pyo3(signature = (session, command, options=None))]
     pub async fn run_command_with_session(
         &self,
@@ -271,6 +313,90 @@ impl CoreDatabase {
         spawn(fut).await?
     }
 
+    /// Like `run_command`, but for commands that answer with a `cursor` sub-document
+    /// (`listIndexes`, `aggregate`, `collStats` variants, ...) instead of a single reply: the
+    /// first batch is seeded from the command reply and subsequent batches are pulled via
+    /// `getMore` against the returned namespace.
+    #[pyo3(signature = (command, options=None))]
+    pub async fn run_cursor_command(
+        &self,
+        command: CoreDocument,
+        options: Option<CoreRunCursorCommandOptions>,
+    ) -> PyResult<CoreCursor> {
+        let db = self.db.clone();
+
+        let command: Document = command.into();
+        let options = options.unwrap_or_default();
+        let selection_criteria: Option<SelectionCriteria> = options
+            .read_preference
+            .map(|p| SelectionCriteria::ReadPreference(p));
+
+        debug!(
+            "{:?}.run_cursor_command, command: {:?}",
+            self.name, command
+        );
+
+        let fut = async move {
+            let cur = db
+                .run_cursor_command(command)
+                .optional(selection_criteria, |cmd, sc| cmd.selection_criteria(sc))
+                .optional(options.batch_size, |cmd, bs| cmd.batch_size(bs))
+                .optional(options.max_time_ms, |cmd, ms| {
+                    cmd.max_time(std::time::Duration::from_millis(ms))
+                })
+                .optional(options.comment, |cmd, c| cmd.comment(c))
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreCursor::new(cur.with_type()))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Session-bound sibling of `run_cursor_command`, following the same `_with_session`
+    /// pattern used by `aggregate_with_session` and `find_with_session`.
+    #[pyo3(signature = (session, command, options=None))]
+    pub async fn run_cursor_command_with_session(
+        &self,
+        session: Py<CoreSession>,
+        command: CoreDocument,
+        options: Option<CoreRunCursorCommandOptions>,
+    ) -> PyResult<CoreSessionCursor> {
+        let db = self.db.clone();
+
+        let command: Document = command.into();
+        let options = options.unwrap_or_default();
+        let selection_criteria: Option<SelectionCriteria> = options
+            .read_preference
+            .map(|p| SelectionCriteria::ReadPreference(p));
+
+        debug!(
+            "{:?}.run_cursor_command_with_session, command: {:?}",
+            self.name, command
+        );
+
+        let session = Python::with_gil(|py| session.borrow(py).session.clone());
+
+        let fut = async move {
+            let cur = db
+                .run_cursor_command(command)
+                .optional(selection_criteria, |cmd, sc| cmd.selection_criteria(sc))
+                .optional(options.batch_size, |cmd, bs| cmd.batch_size(bs))
+                .optional(options.max_time_ms, |cmd, ms| {
+                    cmd.max_time(std::time::Duration::from_millis(ms))
+                })
+                .optional(options.comment, |cmd, c| cmd.comment(c))
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreSessionCursor::new(cur.with_type(), Arc::clone(&session)))
+        };
+
+        spawn(fut).await?
+    }
+
     #[pyo3(signature = (pipeline, options=None))]
     pub async fn aggregate(
         &self,
@@ -334,6 +460,70 @@ impl CoreDatabase {
         spawn(fut).await?
     }
 
+    #[pyo3(signature = (pipeline=None, options=None))]
+    pub async fn watch(
+        &self,
+        pipeline: Option<CorePipeline>,
+        options: Option<CoreChangeStreamOptions>,
+    ) -> PyResult<CoreChangeStream> {
+        let db = self.db.clone();
+
+        let pipeline: Vec<Document> = pipeline.map(Into::into).unwrap_or_default();
+        let options: Option<ChangeStreamOptions> = options.map(Into::into);
+
+        debug!(
+            "{:?}.watch, pipeline: {:?}, options: {:?}",
+            self.name, pipeline, options
+        );
+
+        let fut = async move {
+            let stream = db
+                .watch()
+                .pipeline(pipeline)
+                .with_options(options)
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreChangeStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    #[pyo3(signature = (session, pipeline=None, options=None))]
+    pub async fn watch_with_session(
+        &self,
+        session: Py<CoreSession>,
+        pipeline: Option<CorePipeline>,
+        options: Option<CoreChangeStreamOptions>,
+    ) -> PyResult<CoreChangeStream> {
+        let db = self.db.clone();
+
+        let pipeline: Vec<Document> = pipeline.map(Into::into).unwrap_or_default();
+        let options: Option<ChangeStreamOptions> = options.map(Into::into);
+
+        debug!(
+            "{:?}.watch_with_session, pipeline: {:?}, options: {:?}",
+            self.name, pipeline, options
+        );
+
+        let session = Python::with_gil(|py| session.borrow(py).session.clone());
+
+        let fut = async move {
+            let stream = db
+                .watch()
+                .pipeline(pipeline)
+                .with_options(options)
+                .session(session.lock().await.deref_mut())
+                .await
+                .map_err(|e| MongoError::from(e))?;
+
+            Ok(CoreChangeStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
     #[pyo3(signature = (options=None))]
     pub fn gridfs_bucket(
         &self,
@@ -1,8 +1,11 @@
-use bson::{Document, RawDocumentBuf};
+use bson::{Bson, Document, RawArrayBuf, RawBson, RawDocumentBuf};
 use mongodb::options::UpdateModifications;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
+use serde::Serialize;
+
+use crate::conv::{from_py_raw_array, from_py_raw_document, into_py_object, into_py_raw_document};
 
 #[derive(Debug, Clone)]
 pub struct CoreDocument(pub Document);
@@ -111,6 +114,25 @@ impl<'py> FromPyObject<'_, 'py> for CoreCompoundDocument {
     }
 }
 
+impl CoreCompoundDocument {
+    /// Update documents must contain only atomic operators; pipeline-form updates have no such
+    /// restriction. Reject a plain document with a non-`$` top-level key up front, mirroring
+    /// `CoreRawDocument::ensure_no_update_operators`'s pre-flight check for replacements.
+    pub fn ensure_valid_update(&self) -> PyResult<()> {
+        let CoreCompoundDocument::Doc(doc) = self else {
+            return Ok(());
+        };
+
+        if doc.0.keys().any(|key| !key.starts_with('$')) {
+            return Err(PyValueError::new_err(
+                "update document must only contain atomic operators",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Into<UpdateModifications> for CoreCompoundDocument {
     fn into(self) -> UpdateModifications {
         match self {
@@ -136,23 +158,67 @@ impl Into<RawDocumentBuf> for CoreRawDocument {
     }
 }
 
-impl<'py> IntoPyObject<'py> for CoreRawDocument {
-    type Target = PyBytes;
-    type Output = Bound<'py, Self::Target>;
-    type Error = std::convert::Infallible;
+into_py_raw_document!(CoreRawDocument);
+from_py_raw_document!(CoreRawDocument);
 
-    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        Ok(PyBytes::new(py, self.0.as_bytes()))
+impl CoreRawDocument {
+    /// Replacement documents must not contain update operators. Reject it up front,
+    /// mirroring the check the official driver performs before sending a replace, instead of
+    /// letting the server round-trip fail with a less actionable error.
+    pub fn ensure_no_update_operators(&self) -> PyResult<()> {
+        ensure_no_update_operators(&self.0)
     }
 }
 
-impl<'py> FromPyObject<'_, 'py> for CoreRawDocument {
-    type Error = PyErr;
+pub fn ensure_no_update_operators(doc: &RawDocumentBuf) -> PyResult<()> {
+    let first_key = doc
+        .iter()
+        .next()
+        .transpose()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?
+        .map(|(k, _)| k);
+
+    if let Some(key) = first_key {
+        if key.starts_with('$') {
+            return Err(PyValueError::new_err(
+                "replacement document must not contain atomic operators",
+            ));
+        }
+    }
 
-    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
-        let data = obj.extract::<&[u8]>()?;
-        let doc = RawDocumentBuf::from_bytes(data.into())
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
-        Ok(CoreRawDocument(doc))
+    Ok(())
+}
+
+/// A list of raw documents (e.g. `insert_many`'s `documents`), built straight into a
+/// `RawArrayBuf` from Python bytes without ever decoding a `bson::Document` along the way.
+#[derive(Debug, Clone)]
+pub struct CoreRawDocumentArray(RawArrayBuf);
+
+from_py_raw_array!(CoreRawDocumentArray);
+
+impl CoreRawDocumentArray {
+    /// Splits the raw array back out into the individual `RawDocumentBuf`s the driver's
+    /// `insert_many` expects, one per element.
+    pub fn into_documents(self) -> PyResult<Vec<RawDocumentBuf>> {
+        self.0
+            .into_iter()
+            .map(|item| match item.map_err(|e| PyValueError::new_err(e.to_string()))? {
+                RawBson::Document(doc) => Ok(doc),
+                other => Err(PyValueError::new_err(format!(
+                    "expected a document in the array, got {:?}",
+                    other
+                ))),
+            })
+            .collect()
     }
 }
+
+/// One row of a `CoreCursor::collect_projected` result: the requested fields' values, in the
+/// order they were asked for, with missing fields coming back as `Bson::Null` rather than
+/// shrinking the row.
+#[derive(Debug, Clone, Serialize)]
+pub struct CoreProjectedDocument {
+    pub values: Vec<Bson>,
+}
+
+into_py_object!(CoreProjectedDocument);
@@ -1,7 +1,8 @@
 use std::fmt::{Display, Formatter};
 
-use mongodb::error::{ErrorKind, GridFsErrorKind, WriteFailure};
+use mongodb::error::{BulkWriteFailure, ErrorKind, GridFsErrorKind, WriteFailure};
 use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
 use pyo3::{create_exception, PyErr};
 
 create_exception!(
@@ -108,7 +109,11 @@ impl From<mongodb::error::Error> for MongoError {
 impl From<MongoError> for PyErr {
     fn from(value: MongoError) -> Self {
         let msg = value.clone().to_string();
-        match *value.0.kind {
+        let code = value.code();
+        let code_name = value.code_name();
+        let labels = value.labels();
+
+        let err = match *value.0.kind {
             // ErrorKind::InvalidArgument { .. } => ConfigurationError::new_err(msg),
             ErrorKind::InvalidArgument { .. } => PyValueError::new_err(msg),
             ErrorKind::Authentication { .. } => ConfigurationError::new_err(msg),
@@ -138,6 +143,77 @@ impl From<MongoError> for PyErr {
                 _ => GridFSError::new_err(msg),
             },
             _ => PyMongoError::new_err(msg),
+        };
+
+        attach_error_details(err, code, code_name, labels)
+    }
+}
+
+/// Surfaces the server's numeric `code`, `codeName`, and error labels (e.g.
+/// `RetryableWriteError`, `TransientTransactionError`, `UnknownTransactionCommitResult`) as
+/// attributes on the raised exception instance, so callers can branch on specific conditions
+/// (e.g. code 85 `IndexOptionsConflict`) instead of substring-matching the message. A label
+/// check is `label in exc.labels`.
+fn attach_error_details(
+    err: PyErr,
+    code: Option<i32>,
+    code_name: Option<String>,
+    labels: Vec<String>,
+) -> PyErr {
+    Python::with_gil(|py| {
+        let value = err.value(py);
+        let _ = value.setattr("code", code);
+        let _ = value.setattr("code_name", code_name);
+        let _ = value.setattr("labels", labels);
+    });
+
+    err
+}
+
+impl MongoError {
+    /// The server's numeric error code, from `ErrorKind::Command`/`WriteFailure::WriteError`/
+    /// `WriteFailure::WriteConcernError`; `None` for client-side failures that never reached a
+    /// command response.
+    pub fn code(&self) -> Option<i32> {
+        match &*self.0.kind {
+            ErrorKind::Command(c) => Some(c.code),
+            ErrorKind::Write(WriteFailure::WriteError(w)) => Some(w.code),
+            ErrorKind::Write(WriteFailure::WriteConcernError(w)) => Some(w.code),
+            _ => None,
+        }
+    }
+
+    /// The server's symbolic error name (e.g. `"MaxTimeMSExpired"`), mirroring `code`.
+    pub fn code_name(&self) -> Option<String> {
+        match &*self.0.kind {
+            ErrorKind::Command(c) => Some(c.code_name.clone()),
+            ErrorKind::Write(WriteFailure::WriteError(w)) => Some(w.code_name.clone()),
+            ErrorKind::Write(WriteFailure::WriteConcernError(w)) => Some(w.code_name.clone()),
+            _ => None,
+        }
+    }
+
+    /// The driver's error labels (e.g. `RetryableWriteError`, `TransientTransactionError`).
+    pub fn labels(&self) -> Vec<String> {
+        self.0.labels().iter().cloned().collect()
+    }
+
+    /// True if `commit_transaction` can be safely retried for this failure: labeled
+    /// `UnknownTransactionCommitResult` and not a terminal `MaxTimeMSExpired` (code 50) case,
+    /// per the drivers' convenient-transactions commit-retry algorithm.
+    pub fn is_retryable_commit_error(&self) -> bool {
+        self.0.contains_label("UnknownTransactionCommitResult") && self.code() != Some(50)
+    }
+
+    /// The per-document write errors and write concern error from a partially-failed bulk
+    /// write (e.g. `insert_many` failing partway through a batch), if this error actually
+    /// originated from one. The driver doesn't report which documents before the failure
+    /// point were inserted, so callers can recover per-index error detail but not a partial
+    /// `inserted_ids` list from this alone.
+    pub fn bulk_write_failure(&self) -> Option<&BulkWriteFailure> {
+        match &*self.0.kind {
+            ErrorKind::BulkWrite(failure) => Some(failure),
+            _ => None,
         }
     }
 }
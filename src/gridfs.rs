@@ -1,13 +1,21 @@
+use std::sync::Arc;
+
+use crate::cursor::CoreCursor;
 use crate::document::CoreDocument;
-use crate::error::MongoError;
-use crate::options::{CoreGridFsGetByIdOptions, CoreGridFsGetByNameOptions, CoreGridFsPutOptions};
+use crate::error::{FileExists, MongoError};
+use crate::options::{
+    CoreGridFsFindOptions, CoreGridFsGetByIdOptions, CoreGridFsGetByNameOptions,
+    CoreGridFsPutOptions,
+};
 use crate::runtime::spawn;
-use bson::{doc, Document};
+use bson::{doc, Bson, Document};
 use futures::{AsyncReadExt, AsyncWriteExt};
 use log::debug;
-use mongodb::gridfs::GridFsBucket;
-use mongodb::options::GridFsUploadOptions;
+use mongodb::gridfs::{GridFsBucket, GridFsDownloadStream, GridFsUploadStream};
+use mongodb::options::{GridFsFindOptions, GridFsUploadOptions};
 use pyo3::prelude::*;
+use tokio::io::{AsyncReadExt as TokioAsyncReadExt, AsyncWriteExt as TokioAsyncWriteExt};
+use tokio::sync::Mutex;
 
 #[pyclass]
 pub struct CoreGridFsBucket {
@@ -36,7 +44,11 @@ impl CoreGridFsBucket {
         );
 
         let metadata: Option<Document> = metadata.map(Into::into);
-        let upload_options = GridFsUploadOptions::builder().metadata(metadata).build();
+        let chunk_size_bytes = options.as_ref().and_then(|o| o.chunk_size_bytes);
+        let upload_options = GridFsUploadOptions::builder()
+            .metadata(metadata)
+            .chunk_size_bytes(chunk_size_bytes)
+            .build();
 
         // let file_id = options.clone().and_then(|o| o.file_id);
         let file_id = options.as_ref().and_then(|o| o.file_id.clone());
@@ -96,17 +108,22 @@ impl CoreGridFsBucket {
         spawn(fut).await?
     }
 
+    /// `options.revision` selects which version of `filename` to fetch: `0` is the oldest, `-1`
+    /// (the default) the newest, positive values count forward and negative values count back,
+    /// per the GridFS spec.
     pub async fn get_by_name(&self, options: CoreGridFsGetByNameOptions) -> PyResult<Vec<u8>> {
         let bucket = self.bucket.clone();
 
         debug!("gridfs.get_by_name, options: {:?}", options);
 
         let filename = options.filename;
+        let revision = options.revision;
 
         let fut = async move {
             let mut buf = Vec::new();
             let mut download_stream = bucket
                 .open_download_stream_by_name(filename)
+                .revision(revision)
                 .await
                 .map_err(MongoError::from)?;
             download_stream
@@ -134,4 +151,397 @@ impl CoreGridFsBucket {
 
         spawn(fut).await?
     }
+
+    /// Drops the bucket's underlying `files` and `chunks` collections.
+    pub async fn drop(&self) -> PyResult<()> {
+        let bucket = self.bucket.clone();
+
+        debug!("gridfs.drop");
+
+        let fut = async move {
+            bucket.drop().await.map_err(MongoError::from)?;
+
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
+
+    pub async fn rename(&self, file_id: Bson, new_filename: String) -> PyResult<()> {
+        let bucket = self.bucket.clone();
+
+        debug!(
+            "gridfs.rename, file_id: {:?}, new_filename: {:?}",
+            file_id, new_filename
+        );
+
+        let fut = async move {
+            bucket
+                .rename(file_id, new_filename)
+                .await
+                .map_err(MongoError::from)?;
+
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Opens a chunked upload handle instead of buffering the whole file in memory: callers
+    /// push data via repeated `write` calls and finalize with `close`.
+    pub async fn open_upload_stream(
+        &self,
+        options: Option<CoreGridFsPutOptions>,
+    ) -> PyResult<CoreGridFsUploadStream> {
+        let bucket = self.bucket.clone();
+
+        debug!("gridfs.open_upload_stream, options: {:?}", options);
+
+        let file_id = options.as_ref().and_then(|o| o.file_id.clone());
+        let chunk_size_bytes = options.as_ref().and_then(|o| o.chunk_size_bytes);
+        let metadata: Option<Document> = options.as_ref().and_then(|o| o.metadata.clone());
+        let filename = options.and_then(|o| o.filename).unwrap_or_default();
+
+        let upload_options = GridFsUploadOptions::builder()
+            .chunk_size_bytes(chunk_size_bytes)
+            .metadata(metadata)
+            .build();
+
+        let fut = async move {
+            let stream = if let Some(id) = file_id {
+                bucket
+                    .open_upload_stream(filename)
+                    .id(id)
+                    .with_options(upload_options)
+                    .await
+                    .map_err(MongoError::from)?
+            } else {
+                bucket
+                    .open_upload_stream(filename)
+                    .with_options(upload_options)
+                    .await
+                    .map_err(MongoError::from)?
+            };
+
+            Ok(CoreGridFsUploadStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Lists/searches the bucket's `files` collection instead of fetching one file by id or
+    /// name, so Python callers can paginate a bucket's contents or look files up by custom
+    /// `metadata` fields. Yielded documents are the files-collection metadata records (`_id`,
+    /// `filename`, `length`, `chunkSize`, `uploadDate`, `metadata`), not file contents.
+    pub async fn find(
+        &self,
+        filter: Option<CoreDocument>,
+        options: Option<CoreGridFsFindOptions>,
+    ) -> PyResult<CoreCursor> {
+        let bucket = self.bucket.clone();
+
+        let filter: Option<Document> = filter.map(Into::into);
+        let options: Option<GridFsFindOptions> = options.map(Into::into);
+
+        debug!("gridfs.find, filter: {:?}, options: {:?}", filter, options);
+
+        let fut = async move {
+            let cur = bucket
+                .find(filter.unwrap_or_default())
+                .with_options(options)
+                .await
+                .map_err(MongoError::from)?;
+
+            Ok(CoreCursor::new(cur.with_type()))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Opens a chunked download handle that pulls `chunks` lazily instead of reading the whole
+    /// file into memory up front.
+    pub async fn open_download_stream(
+        &self,
+        options: CoreGridFsGetByIdOptions,
+    ) -> PyResult<CoreGridFsDownloadStream> {
+        let bucket = self.bucket.clone();
+
+        debug!("gridfs.open_download_stream, options: {:?}", options);
+
+        let file_id = options.file_id;
+
+        let fut = async move {
+            let stream = bucket
+                .open_download_stream(file_id)
+                .await
+                .map_err(MongoError::from)?;
+
+            Ok(CoreGridFsDownloadStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Same as `open_download_stream`, but looks the file up by `filename`/`revision` the way
+    /// `get_by_name` does, instead of requiring the caller already know the file's id.
+    pub async fn open_download_stream_by_name(
+        &self,
+        options: CoreGridFsGetByNameOptions,
+    ) -> PyResult<CoreGridFsDownloadStream> {
+        let bucket = self.bucket.clone();
+
+        debug!("gridfs.open_download_stream_by_name, options: {:?}", options);
+
+        let filename = options.filename;
+        let revision = options.revision;
+
+        let fut = async move {
+            let stream = bucket
+                .open_download_stream_by_name(filename)
+                .revision(revision)
+                .await
+                .map_err(MongoError::from)?;
+
+            Ok(CoreGridFsDownloadStream::new(stream))
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Streams `file_id` straight onto disk at `path` without ever buffering the whole file in
+    /// Rust or Python memory. Refuses to clobber an existing `path`, and opens the download
+    /// before creating `path` so a missing file (`NoFile`) leaves no empty stub behind.
+    pub async fn download_to_path(
+        &self,
+        options: CoreGridFsGetByIdOptions,
+        path: String,
+    ) -> PyResult<()> {
+        let bucket = self.bucket.clone();
+
+        debug!(
+            "gridfs.download_to_path, options: {:?}, path: {:?}",
+            options, path
+        );
+
+        let file_id = options.file_id;
+
+        let fut = async move {
+            let mut download_stream = bucket
+                .open_download_stream(file_id)
+                .await
+                .map_err(MongoError::from)?;
+
+            let mut file = match tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .await
+            {
+                Ok(file) => file,
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    return Err(FileExists::new_err(format!("{:?} already exists", path)));
+                }
+                Err(e) => return Err(MongoError::from(e).into()),
+            };
+
+            // From here on `path` exists and holds our partial write; clean it up on any
+            // failure so a retry doesn't hit `FileExists` on a truncated, unusable file.
+            let result: PyResult<()> = async {
+                let mut buf = [0u8; 8192];
+                loop {
+                    let read = download_stream
+                        .read(&mut buf)
+                        .await
+                        .map_err(MongoError::from)?;
+                    if read == 0 {
+                        break;
+                    }
+
+                    TokioAsyncWriteExt::write_all(&mut file, &buf[..read])
+                        .await
+                        .map_err(MongoError::from)?;
+                }
+
+                TokioAsyncWriteExt::flush(&mut file)
+                    .await
+                    .map_err(MongoError::from)?;
+
+                Ok(())
+            }
+            .await;
+
+            if result.is_err() {
+                let _ = tokio::fs::remove_file(&path).await;
+            }
+
+            result
+        };
+
+        spawn(fut).await?
+    }
+
+    /// Streams `path` straight into a new GridFS file without ever buffering the whole file in
+    /// Rust or Python memory -- the upload counterpart to `download_to_path`.
+    pub async fn upload_from_path(
+        &self,
+        path: String,
+        options: Option<CoreGridFsPutOptions>,
+        metadata: Option<CoreDocument>,
+    ) -> PyResult<CoreDocument> {
+        let bucket = self.bucket.clone();
+
+        debug!(
+            "gridfs.upload_from_path, path: {:?}, options: {:?}, metadata: {:?}",
+            path, options, metadata
+        );
+
+        let metadata: Option<Document> = metadata.map(Into::into);
+        let file_id = options.as_ref().and_then(|o| o.file_id.clone());
+        let chunk_size_bytes = options.as_ref().and_then(|o| o.chunk_size_bytes);
+        let filename = options.and_then(|o| o.filename).unwrap_or_default();
+
+        let upload_options = GridFsUploadOptions::builder()
+            .metadata(metadata)
+            .chunk_size_bytes(chunk_size_bytes)
+            .build();
+
+        let fut = async move {
+            let mut file = tokio::fs::File::open(&path)
+                .await
+                .map_err(MongoError::from)?;
+
+            let mut upload_stream = if let Some(id) = file_id {
+                bucket
+                    .open_upload_stream(filename)
+                    .id(id)
+                    .with_options(upload_options)
+                    .await
+                    .map_err(MongoError::from)?
+            } else {
+                bucket
+                    .open_upload_stream(filename)
+                    .with_options(upload_options)
+                    .await
+                    .map_err(MongoError::from)?
+            };
+
+            let mut buf = [0u8; 8192];
+            loop {
+                let read = TokioAsyncReadExt::read(&mut file, &mut buf)
+                    .await
+                    .map_err(MongoError::from)?;
+                if read == 0 {
+                    break;
+                }
+
+                upload_stream
+                    .write_all(&buf[..read])
+                    .await
+                    .map_err(MongoError::from)?;
+            }
+
+            upload_stream.close().await.map_err(MongoError::from)?;
+
+            let result: CoreDocument = doc! {"file_id": upload_stream.id()}.into();
+            Ok(result)
+        };
+
+        spawn(fut).await?
+    }
+}
+
+/// Chunked GridFS upload handle. `write` appends data to the driver's upload stream, which
+/// writes each full chunk to the `chunks` collection; `close` flushes the trailing partial
+/// chunk and writes the `files` document.
+#[pyclass]
+pub struct CoreGridFsUploadStream {
+    stream: Arc<Mutex<GridFsUploadStream>>,
+}
+
+impl CoreGridFsUploadStream {
+    pub fn new(stream: GridFsUploadStream) -> Self {
+        Self {
+            stream: Arc::new(Mutex::new(stream)),
+        }
+    }
+}
+
+#[pymethods]
+impl CoreGridFsUploadStream {
+    pub async fn write(&self, data: Vec<u8>) -> PyResult<()> {
+        let stream = Arc::clone(&self.stream);
+
+        let fut = async move {
+            stream
+                .lock()
+                .await
+                .write_all(&data[..])
+                .await
+                .map_err(MongoError::from)?;
+
+            Ok(())
+        };
+
+        spawn(fut).await?
+    }
+
+    pub async fn close(&self) -> PyResult<CoreDocument> {
+        let stream = Arc::clone(&self.stream);
+
+        let fut = async move {
+            let mut stream = stream.lock().await;
+            stream.close().await.map_err(MongoError::from)?;
+
+            let result: CoreDocument = doc! {"file_id": stream.id()}.into();
+            Ok(result)
+        };
+
+        spawn(fut).await?
+    }
+}
+
+/// Chunked GridFS download handle. `read` pulls at most `size` bytes, following `chunks`
+/// ordered by `n`, and returns an empty buffer once the file is exhausted.
+#[pyclass]
+pub struct CoreGridFsDownloadStream {
+    stream: Arc<Mutex<GridFsDownloadStream>>,
+}
+
+impl CoreGridFsDownloadStream {
+    pub fn new(stream: GridFsDownloadStream) -> Self {
+        Self {
+            stream: Arc::new(Mutex::new(stream)),
+        }
+    }
+}
+
+#[pymethods]
+impl CoreGridFsDownloadStream {
+    #[pyo3(signature = (size=None))]
+    pub async fn read(&self, size: Option<usize>) -> PyResult<Vec<u8>> {
+        let stream = Arc::clone(&self.stream);
+
+        let fut = async move {
+            let mut stream = stream.lock().await;
+
+            match size {
+                Some(size) => {
+                    let mut buf = vec![0u8; size];
+                    let read = stream.read(&mut buf).await.map_err(MongoError::from)?;
+                    buf.truncate(read);
+                    Ok(buf)
+                }
+                None => {
+                    let mut buf = Vec::new();
+                    stream
+                        .read_to_end(&mut buf)
+                        .await
+                        .map_err(MongoError::from)?;
+                    Ok(buf)
+                }
+            }
+        };
+
+        spawn(fut).await?
+    }
 }
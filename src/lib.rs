@@ -1,3 +1,5 @@
+mod bulk;
+mod change_stream;
 mod client;
 mod collection;
 mod conv;
@@ -17,10 +19,13 @@ use crate::error::{
     BsonDeserializationError, BsonSerializationError, ConfigurationError, ConnectionFailure,
     FileExists, GridFSError, NoFile, ServerSelectionError,
 };
+use change_stream::CoreChangeStream;
 use client::{core_create_client, CoreClient};
 use collection::CoreCollection;
 use cursor::CoreCursor;
 use database::CoreDatabase;
+use gridfs::{CoreGridFsBucket, CoreGridFsDownloadStream, CoreGridFsUploadStream};
+use runtime::configure_runtime;
 use error::{DuplicateKeyError, OperationFailure, PyMongoError, WriteConcernError, WriteError};
 
 #[rustfmt::skip]
@@ -31,11 +36,16 @@ fn mongojet(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // }
 
     m.add_function(wrap_pyfunction!(core_create_client, m)?)?;
+    m.add_function(wrap_pyfunction!(configure_runtime, m)?)?;
 
     m.add_class::<CoreClient>()?;
     m.add_class::<CoreDatabase>()?;
     m.add_class::<CoreCollection>()?;
     m.add_class::<CoreCursor>()?;
+    m.add_class::<CoreChangeStream>()?;
+    m.add_class::<CoreGridFsBucket>()?;
+    m.add_class::<CoreGridFsUploadStream>()?;
+    m.add_class::<CoreGridFsDownloadStream>()?;
 
     m.add("PyMongoError", m.py().get_type::<PyMongoError>())?;
     m.add("OperationFailure", m.py().get_type::<OperationFailure>())?;
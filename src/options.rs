@@ -1,18 +1,20 @@
 use std::time::Duration;
 
-use bson::{Bson, Document};
+use bson::{doc, Bson, Document, Timestamp};
 use mongodb::options::{
-    AggregateOptions, ChangeStreamPreAndPostImages, ClusteredIndex, Collation, CollectionOptions,
-    CommitQuorum, CountOptions, CreateCollectionOptions, CreateIndexOptions, CursorType,
-    DatabaseOptions, DeleteOptions, DistinctOptions, DropCollectionOptions, DropDatabaseOptions,
-    DropIndexOptions, EstimatedDocumentCountOptions, FindOneAndDeleteOptions,
-    FindOneAndReplaceOptions, FindOneAndUpdateOptions, FindOneOptions, FindOptions,
-    GridFsBucketOptions, Hint, IndexOptionDefaults, InsertManyOptions, InsertOneOptions,
-    ListCollectionsOptions, ListIndexesOptions, ReadConcern, ReadPreference, ReplaceOptions,
-    ReturnDocument, SelectionCriteria, SessionOptions, TimeseriesOptions, TransactionOptions,
-    UpdateOptions, ValidationAction, ValidationLevel, WriteConcern,
+    AggregateOptions, ChangeStreamOptions, ChangeStreamPreAndPostImages, ClusteredIndex,
+    Collation, CollectionOptions, CommitQuorum, CountOptions, CreateCollectionOptions,
+    CreateIndexOptions, CursorType, DatabaseOptions, DeleteOptions, DistinctOptions,
+    DropCollectionOptions, DropDatabaseOptions, DropIndexOptions, EstimatedDocumentCountOptions,
+    FindOneAndDeleteOptions, FindOneAndReplaceOptions, FindOneAndUpdateOptions, FindOneOptions,
+    FindOptions, FullDocumentBeforeChangeType, FullDocumentType, GridFsBucketOptions,
+    GridFsFindOptions, Hint, IndexOptionDefaults, InsertManyOptions, InsertOneOptions,
+    ListCollectionsOptions,
+    ListIndexesOptions, ReadConcern, ReadPreference, ReplaceOptions, ReturnDocument,
+    SelectionCriteria, SessionOptions, TimeseriesOptions, TransactionOptions, UpdateOptions,
+    ValidationAction, ValidationLevel, WriteConcern,
 };
-use mongodb::IndexModel;
+use mongodb::{IndexModel, SearchIndexModel, SearchIndexType};
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -182,6 +184,9 @@ impl Into<FindOneOptions> for CoreFindOneOptions {
     }
 }
 
+/// `return_document` selects whether `find_one_and_update` hands back the pre- or
+/// post-modification document (`ReturnDocument::Before`/`After`), the same choice
+/// `CoreFindOneAndReplaceOptions` offers for replacements.
 #[derive(Clone, Debug, Deserialize)]
 pub struct CoreFindOneAndUpdateOptions {
     pub projection: Option<Document>,
@@ -535,6 +540,11 @@ impl Into<DistinctOptions> for CoreDistinctOptions {
     }
 }
 
+/// Passes straight through to the driver's own `IndexModel`/`IndexOptions`, so every option
+/// they support -- `expireAfterSeconds` TTL indexes, `unique`/`sparse`, `partialFilterExpression`,
+/// `collation`, `weights`/`defaultLanguage`/`languageOverride` for `text` indexes, and
+/// `2dsphere`/`2d` keys with `bits`/`min`/`max` -- is already available from Python without a
+/// bespoke builder duplicating the driver's field set.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CoreIndexModel(IndexModel);
 
@@ -553,6 +563,35 @@ impl Into<IndexModel> for CoreIndexModel {
     }
 }
 
+/// An Atlas Search / Vector Search index, a distinct namespace from the `IndexModel` above with
+/// its own admin commands. `index_type` selects between `"search"` (full-text) and
+/// `"vectorSearch"`; `definition` is the free-form analyzer/field-mapping document Atlas expects
+/// and is passed through untouched.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoreSearchIndexModel {
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub index_type: Option<String>,
+    pub definition: Document,
+}
+
+from_py_object!(CoreSearchIndexModel);
+
+impl Into<SearchIndexModel> for CoreSearchIndexModel {
+    fn into(self) -> SearchIndexModel {
+        let index_type = self.index_type.map(|t| match t.as_str() {
+            "vectorSearch" => SearchIndexType::VectorSearch,
+            _ => SearchIndexType::Search,
+        });
+
+        SearchIndexModel::builder()
+            .name(self.name)
+            .index_type(index_type)
+            .definition(self.definition)
+            .build()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoreCreateIndexOptions {
@@ -576,6 +615,17 @@ impl Into<CreateIndexOptions> for CoreCreateIndexOptions {
     }
 }
 
+/// Options for `CoreCollection::sync_indexes`. `prune`, when set, also drops any existing
+/// non-`_id_` index that isn't present among the desired models; otherwise unmatched existing
+/// indexes are left alone and only the desired set is created/recreated.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CoreSyncIndexesOptions {
+    #[serde(default)]
+    pub prune: Option<bool>,
+}
+
+from_py_object!(CoreSyncIndexesOptions);
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoreDropIndexOptions {
@@ -733,6 +783,8 @@ impl Into<CreateCollectionOptions> for CoreCreateCollectionOptions {
 pub struct CoreListCollectionsOptions {
     pub batch_size: Option<u32>,
     pub comment: Option<Bson>,
+    pub name_only: Option<bool>,
+    pub authorized_collections: Option<bool>,
 }
 
 from_py_object!(CoreListCollectionsOptions);
@@ -742,6 +794,8 @@ impl Into<ListCollectionsOptions> for CoreListCollectionsOptions {
         ListCollectionsOptions::builder()
             .batch_size(self.batch_size)
             .comment(self.comment)
+            .name_only(self.name_only)
+            .authorized_collections(self.authorized_collections)
             .build()
     }
 }
@@ -753,6 +807,16 @@ pub struct CoreRunCommandOptions {
 
 from_py_object!(CoreRunCommandOptions);
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CoreRunCursorCommandOptions {
+    pub read_preference: Option<ReadPreference>,
+    pub batch_size: Option<u32>,
+    pub max_time_ms: Option<u64>,
+    pub comment: Option<Bson>,
+}
+
+from_py_object!(CoreRunCursorCommandOptions);
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CoreGridFsBucketOptions {
     pub bucket_name: Option<String>,
@@ -780,10 +844,51 @@ impl Into<GridFsBucketOptions> for CoreGridFsBucketOptions {
     }
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoreChangeStreamOptions {
+    pub full_document: Option<FullDocumentType>,
+    pub full_document_before_change: Option<FullDocumentBeforeChangeType>,
+    pub resume_after: Option<Document>,
+    pub start_after: Option<Document>,
+    pub start_at_operation_time: Option<Timestamp>,
+    pub batch_size: Option<u32>,
+    pub max_await_time_ms: Option<u64>,
+    pub collation: Option<Collation>,
+    pub comment: Option<Bson>,
+    pub read_concern: Option<ReadConcern>,
+    pub read_preference: Option<ReadPreference>,
+}
+
+from_py_object!(CoreChangeStreamOptions);
+
+impl Into<ChangeStreamOptions> for CoreChangeStreamOptions {
+    fn into(self) -> ChangeStreamOptions {
+        let selection_criteria: Option<SelectionCriteria> = self
+            .read_preference
+            .map(|p| SelectionCriteria::ReadPreference(p));
+
+        ChangeStreamOptions::builder()
+            .full_document(self.full_document)
+            .full_document_before_change(self.full_document_before_change)
+            .resume_after(self.resume_after.and_then(|d| bson::from_document(d).ok()))
+            .start_after(self.start_after.and_then(|d| bson::from_document(d).ok()))
+            .start_at_operation_time(self.start_at_operation_time)
+            .batch_size(self.batch_size)
+            .max_await_time(self.max_await_time_ms.map(Duration::from_millis))
+            .collation(self.collation)
+            .comment(self.comment)
+            .read_concern(self.read_concern)
+            .selection_criteria(selection_criteria)
+            .build()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CoreGridFsPutOptions {
     pub file_id: Option<Bson>,
     pub filename: Option<String>,
+    pub chunk_size_bytes: Option<u32>,
+    pub metadata: Option<Document>,
 }
 from_py_object!(CoreGridFsPutOptions);
 
@@ -796,9 +901,111 @@ from_py_object!(CoreGridFsGetByIdOptions);
 #[derive(Clone, Debug, Deserialize)]
 pub struct CoreGridFsGetByNameOptions {
     pub filename: String,
+    pub revision: Option<i32>,
 }
 from_py_object!(CoreGridFsGetByNameOptions);
 
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CoreGridFsFindOptions {
+    pub batch_size: Option<u32>,
+    pub limit: Option<i64>,
+    pub skip: Option<u64>,
+    pub sort: Option<Document>,
+    pub max_time_ms: Option<u64>,
+    pub no_cursor_timeout: Option<bool>,
+}
+from_py_object!(CoreGridFsFindOptions);
+
+/// Parameters for an Atlas `$vectorSearch` stage, mirroring the server's stage schema so
+/// callers don't have to hand-build the aggregation document.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CoreVectorSearchParams {
+    pub index: String,
+    pub path: String,
+    pub query_vector: Vec<f64>,
+    pub limit: i64,
+    /// Candidates to examine before ranking; Atlas recommends 10-20x `limit`. Defaults to
+    /// `limit * 10` when omitted.
+    #[serde(default)]
+    pub num_candidates: Option<i64>,
+    #[serde(default)]
+    pub filter: Option<Document>,
+    /// Switches `$vectorSearch` into ENN (exact nearest neighbor) mode instead of ANN. When
+    /// `true`, `numCandidates` is omitted from the stage even if set, since Atlas rejects the
+    /// two together.
+    #[serde(default)]
+    pub exact: Option<bool>,
+    /// When set, the similarity score is attached to each result document under this field
+    /// name via `$addFields` rather than being dropped on the floor.
+    #[serde(default)]
+    pub score_field_name: Option<String>,
+}
+from_py_object!(CoreVectorSearchParams);
+
+impl CoreVectorSearchParams {
+    pub fn into_pipeline(self) -> Vec<Document> {
+        let mut stage = doc! {
+            "index": self.index,
+            "path": self.path,
+            "queryVector": self.query_vector,
+            "limit": self.limit,
+        };
+
+        // Atlas rejects `numCandidates` alongside `exact: true` -- ENN mode scans every
+        // candidate and has no notion of a candidate pool to narrow first.
+        if self.exact != Some(true) {
+            let num_candidates = self.num_candidates.unwrap_or(self.limit * 10);
+            stage.insert("numCandidates", num_candidates);
+        }
+
+        if let Some(filter) = self.filter {
+            stage.insert("filter", filter);
+        }
+        if let Some(exact) = self.exact {
+            stage.insert("exact", exact);
+        }
+
+        let mut pipeline = vec![doc! { "$vectorSearch": stage }];
+
+        if let Some(score_field_name) = self.score_field_name {
+            pipeline.push(doc! {
+                "$addFields": { score_field_name: { "$meta": "vectorSearchScore" } }
+            });
+        }
+
+        pipeline
+    }
+}
+
+impl Into<GridFsFindOptions> for CoreGridFsFindOptions {
+    fn into(self) -> GridFsFindOptions {
+        GridFsFindOptions::builder()
+            .batch_size(self.batch_size)
+            .limit(self.limit)
+            .skip(self.skip)
+            .sort(self.sort)
+            .max_time(self.max_time_ms.map(Duration::from_millis))
+            .no_cursor_timeout(self.no_cursor_timeout)
+            .build()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CoreBulkWriteOptions {
+    pub ordered: Option<bool>,
+    pub bypass_document_validation: Option<bool>,
+    pub write_concern: Option<WriteConcern>,
+    pub comment: Option<Bson>,
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+    /// When set, `CoreBulkWriteResult.results` is populated with one document per operation
+    /// instead of only the aggregated counts.
+    #[serde(default)]
+    pub verbose_results: Option<bool>,
+}
+
+from_py_object!(CoreBulkWriteOptions);
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CoreDropDatabaseOptions {
     pub write_concern: Option<WriteConcern>,
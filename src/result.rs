@@ -118,6 +118,15 @@ impl From<CreateIndexesResult> for CoreCreateIndexesResult {
     }
 }
 
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CoreSyncIndexesResult {
+    pub created: Vec<String>,
+    pub dropped: Vec<String>,
+    pub recreated: Vec<String>,
+}
+
+into_py_object!(CoreSyncIndexesResult);
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CoreCollectionSpecification {
@@ -143,6 +152,42 @@ impl From<CollectionSpecification> for CoreCollectionSpecification {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct CoreBulkWriteError {
+    pub index: usize,
+    pub message: String,
+}
+
+impl CoreBulkWriteError {
+    pub fn from_index_and_error(index: usize, error: crate::error::MongoError) -> Self {
+        Self {
+            index,
+            message: error.to_string(),
+        }
+    }
+
+    pub fn from_index_and_message(index: usize, message: String) -> Self {
+        Self { index, message }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CoreBulkWriteResult {
+    pub inserted_count: u64,
+    pub matched_count: u64,
+    pub modified_count: u64,
+    pub deleted_count: u64,
+    pub upserted_count: u64,
+    pub inserted_ids: Vec<Bson>,
+    pub upserted_ids: Vec<Bson>,
+    pub write_errors: Vec<CoreBulkWriteError>,
+    /// Per-operation outcomes, populated only when `CoreBulkWriteOptions.verbose_results` is
+    /// set; empty otherwise.
+    pub results: Vec<Document>,
+}
+
+into_py_object!(CoreBulkWriteResult);
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ReadPreferenceResult(ReadPreference);
 
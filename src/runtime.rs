@@ -1,11 +1,88 @@
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use serde::Deserialize;
 use std::future::Future;
+use std::sync::OnceLock;
+
+use crate::conv::from_py_object;
+
+/// Tunables for the global tokio runtime. Left unset, each field falls back to tokio's own
+/// default (number of CPUs for `worker_threads`, 512 for `max_blocking_threads`,
+/// `tokio-runtime-worker` as every thread's name). With `thread_name_prefix` set, threads are
+/// named `<prefix>-0`, `<prefix>-1`, ...
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct CoreRuntimeOptions {
+    pub worker_threads: Option<usize>,
+    pub max_blocking_threads: Option<usize>,
+    pub thread_name_prefix: Option<String>,
+}
+
+from_py_object!(CoreRuntimeOptions);
+
+static RUNTIME_OPTIONS: OnceLock<CoreRuntimeOptions> = OnceLock::new();
+static RT: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+/// Sets the worker-thread count / blocking-pool size / thread name prefix the global runtime
+/// is built with. Must be called before the runtime is first used (e.g. before
+/// `core_create_client`); calling it again with the same settings is a no-op, but calling it
+/// again with different settings once the runtime is already running is an error since tokio
+/// offers no way to rebuild a running runtime in place.
+#[pyfunction]
+pub fn configure_runtime(options: CoreRuntimeOptions) -> PyResult<()> {
+    if options.worker_threads == Some(0) {
+        return Err(PyValueError::new_err("worker_threads must be greater than 0"));
+    }
+    if options.max_blocking_threads == Some(0) {
+        return Err(PyValueError::new_err(
+            "max_blocking_threads must be greater than 0",
+        ));
+    }
+
+    match RUNTIME_OPTIONS.get() {
+        Some(existing) if *existing == options => Ok(()),
+        Some(_) => Err(PyRuntimeError::new_err(
+            "the tokio runtime was already initialized with different settings",
+        )),
+        // The runtime was already built with tokio's defaults by an earlier call.
+        None if RT.get().is_some() && options != CoreRuntimeOptions::default() => {
+            Err(PyRuntimeError::new_err(
+                "the tokio runtime was already initialized with different settings",
+            ))
+        }
+        None => {
+            RUNTIME_OPTIONS
+                .set(options)
+                .map_err(|_| PyRuntimeError::new_err("the tokio runtime is already initializing"))?;
+            // Force the runtime to build now, under the settings we just stored.
+            tokio();
+            Ok(())
+        }
+    }
+}
 
 pub fn tokio() -> &'static tokio::runtime::Runtime {
-    use std::sync::OnceLock;
-    static RT: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
-    RT.get_or_init(|| tokio::runtime::Runtime::new().unwrap())
+    RT.get_or_init(|| {
+        let options = RUNTIME_OPTIONS.get().cloned().unwrap_or_default();
+
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all();
+
+        if let Some(worker_threads) = options.worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+        if let Some(max_blocking_threads) = options.max_blocking_threads {
+            builder.max_blocking_threads(max_blocking_threads);
+        }
+        if let Some(prefix) = options.thread_name_prefix {
+            let next_id = std::sync::atomic::AtomicUsize::new(0);
+            builder.thread_name_fn(move || {
+                let id = next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                format!("{}-{}", prefix, id)
+            });
+        }
+
+        builder.build().unwrap()
+    })
 }
 
 pub async fn spawn<F>(future: F) -> PyResult<F::Output>
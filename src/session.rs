@@ -1,5 +1,6 @@
 use log::debug;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::error::MongoError;
 use crate::options::CoreTransactionOptions;
@@ -47,18 +48,32 @@ impl CoreSession {
         spawn(fut).await?
     }
 
-    pub async fn commit_transaction(&mut self) -> PyResult<()> {
-        debug!("session.commit_transaction");
+    /// Retries the commit alone (never the whole transaction) while the server reports
+    /// `UnknownTransactionCommitResult` and the failure isn't a terminal `MaxTimeMSExpired` case,
+    /// per the drivers' convenient-transactions algorithm. Bounded by `timeout_ms` (default
+    /// 120s), after which the last error is propagated. Committing an empty/already-committed
+    /// transaction is a no-op, inherited from the driver's own `commit_transaction`.
+    #[pyo3(signature = (timeout_ms=None))]
+    pub async fn commit_transaction(&mut self, timeout_ms: Option<u64>) -> PyResult<()> {
+        debug!("session.commit_transaction, timeout_ms: {:?}", timeout_ms);
 
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms.unwrap_or(120_000));
         let s = Arc::clone(&self.session);
+
         let fut = async move {
-            s.lock()
-                .await
-                .commit_transaction()
-                .await
-                .map_err(|e| MongoError::from(e))?;
-            Ok(())
+            loop {
+                match s.lock().await.commit_transaction().await {
+                    Ok(()) => return Ok(()),
+                    Err(e) => {
+                        let err = MongoError::from(e);
+                        if !err.is_retryable_commit_error() || Instant::now() >= deadline {
+                            return Err(err.into());
+                        }
+                    }
+                }
+            }
         };
+
         spawn(fut).await?
     }
 